@@ -45,6 +45,30 @@ impl RangeUtil<i32> for DbInterval {
     }
 }
 
+impl DbInterval {
+    /// Build interval of dB for the range of raw value, so that the minimum and maximum of raw value
+    /// correspond to the given minimum and maximum of dB. Both of them should increase monotonically.
+    pub fn from_valuerange(range: &ValueRange, min_db: i32, max_db: i32)
+        -> Result<Self, ToDbIntervalError>
+    {
+        if range.min >= range.max {
+            let msg = format!("The minimum {} should be less than the maximum {} for raw value",
+                              range.min, range.max);
+            Err(ToDbIntervalError::new(msg))
+        } else if range.step <= 0 || range.length() % range.step > 0 {
+            let msg = format!("The step {} should be positive and divide the length {} of raw value",
+                              range.step, range.length());
+            Err(ToDbIntervalError::new(msg))
+        } else if min_db >= max_db {
+            let msg = format!("The minimum {} should be less than the maximum {} for dB",
+                              min_db, max_db);
+            Err(ToDbIntervalError::new(msg))
+        } else {
+            Ok(DbInterval{min: min_db, max: max_db, linear: false, mute_avail: false})
+        }
+    }
+}
+
 /// The trait for conversion into range of raw value on control element.
 pub trait ToValueRange {
     fn to_valuerange(&self, range: &ValueRange) -> Option<ValueRange>;
@@ -262,6 +286,22 @@ impl ToDbInterval for TlvItem {
 mod test {
     use super::*;
 
+    #[test]
+    fn dbinterval_from_valuerange() {
+        let range = ValueRange{min: -1000, max: 0, step: 1};
+        let interval = DbInterval::from_valuerange(&range, -9400, 0).unwrap();
+        assert_eq!(interval, DbInterval{min: -9400, max: 0, linear: false, mute_avail: false});
+
+        let range = ValueRange{min: 0, max: -1000, step: 1};
+        assert!(DbInterval::from_valuerange(&range, -9400, 0).is_err());
+
+        let range = ValueRange{min: -1000, max: 0, step: 3};
+        assert!(DbInterval::from_valuerange(&range, -9400, 0).is_err());
+
+        let range = ValueRange{min: -1000, max: 0, step: 1};
+        assert!(DbInterval::from_valuerange(&range, 0, -9400).is_err());
+    }
+
     #[test]
     fn to_dbinterval_dbscale() {
         let scale = &DbScale{min: 100, step: 10, mute_avail: true};
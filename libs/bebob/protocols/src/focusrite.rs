@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2021 Takashi Sakamoto
+
+//! Protocol implementation for Focusrite Saffire series based on BeBoB solution.
+//!
+//! The module includes structure, enumeration, and trait and its implementation for protocol
+//! defined by Focusrite for Saffire and Saffire LE.
+
+use super::*;
+
+use hinawa::{FwNode, FwReq, FwReqExtManual, FwTcode};
+
+const BASE_OFFSET: u64 = 0x000100000000;
+
+/// The enumeration for source of sampling clock.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SaffireClkSrc {
+    Internal,
+    Spdif,
+}
+
+impl Default for SaffireClkSrc {
+    fn default() -> Self {
+        Self::Internal
+    }
+}
+
+/// The protocol implementation for media and sampling clock of Saffire and Saffire LE.
+#[derive(Default)]
+pub struct SaffireClkProtocol;
+
+impl MediaClockFrequencyOperation for SaffireClkProtocol {
+    const FREQ_LIST: &'static [u32] = &[44100, 48000, 88200, 96000];
+}
+
+impl SaffireClkProtocol {
+    const CLK_SRC_OFFSET: u64 = 0x00f8;
+
+    const CLK_SRC_INTERNAL: u32 = 0;
+    const CLK_SRC_SPDIF: u32 = 1;
+
+    /// Read current source of sampling clock. It's not available to change it by any known
+    /// transaction.
+    pub fn read_clk_src(
+        req: &FwReq,
+        node: &FwNode,
+        timeout_ms: u32,
+    ) -> Result<SaffireClkSrc, Error> {
+        let mut frame = [0; 4];
+        req.transaction_sync(
+            node,
+            FwTcode::ReadQuadletRequest,
+            BASE_OFFSET + Self::CLK_SRC_OFFSET,
+            frame.len(),
+            &mut frame,
+            timeout_ms,
+        )
+        .and_then(|_| match u32::from_be_bytes(frame) {
+            Self::CLK_SRC_INTERNAL => Ok(SaffireClkSrc::Internal),
+            Self::CLK_SRC_SPDIF => Ok(SaffireClkSrc::Spdif),
+            val => {
+                let msg = format!("Unexpected value for source of clock: {}", val);
+                Err(Error::new(FileError::Io, &msg))
+            }
+        })
+    }
+}
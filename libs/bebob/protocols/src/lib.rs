@@ -12,6 +12,7 @@ pub mod apogee;
 pub mod behringer;
 pub mod digidesign;
 pub mod esi;
+pub mod focusrite;
 pub mod icon;
 pub mod maudio;
 pub mod stanton;
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2020 Takashi Sakamoto
-use glib::Error;
+use glib::{Error, FileError};
 
 use hinawa::{SndUnit, SndUnitExt};
 
@@ -9,7 +9,9 @@ use alsactl::{ElemId, ElemIfaceType, ElemValue};
 use core::card_cntr::CardCntr;
 use core::elem_value_accessor::ElemValueAccessor;
 
-use alsa_ctl_tlv_codec::items::DbInterval;
+use alsa_ctl_tlv_codec::{items::DbInterval, range_utils::ValueRange};
+
+use ta1394::audio::FeatureCtl;
 
 use bebob_protocols::*;
 
@@ -133,12 +135,8 @@ pub trait AvcLevelCtlOperation<T: AvcLevelOperation> {
     const LEVEL_MIN: i32 = T::LEVEL_MIN as i32;
     const LEVEL_MAX: i32 = T::LEVEL_MAX as i32;
     const LEVEL_STEP: i32 = T::LEVEL_STEP as i32;
-    const LEVEL_TLV: DbInterval = DbInterval {
-        min: -12800,
-        max: 0,
-        linear: false,
-        mute_avail: false,
-    };
+    const LEVEL_DB_MIN: i32 = -12800;
+    const LEVEL_DB_MAX: i32 = 0;
 
     fn load_level(&self, card_cntr: &mut CardCntr) -> Result<(), Error> {
         assert_eq!(
@@ -148,6 +146,18 @@ pub trait AvcLevelCtlOperation<T: AvcLevelOperation> {
             Self::LEVEL_NAME
         );
 
+        let range = ValueRange {
+            min: Self::LEVEL_MIN,
+            max: Self::LEVEL_MAX,
+            step: Self::LEVEL_STEP,
+        };
+        let tlv = DbInterval::from_valuerange(&range, Self::LEVEL_DB_MIN, Self::LEVEL_DB_MAX)
+            .map(|tlv| DbInterval {
+                mute_avail: FeatureCtl::is_volume_mute(T::LEVEL_MIN),
+                ..tlv
+            })
+            .map_err(|e| Error::new(FileError::Inval, &e.to_string()))?;
+
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::LEVEL_NAME, 0);
         card_cntr
             .add_int_elems(
@@ -157,7 +167,7 @@ pub trait AvcLevelCtlOperation<T: AvcLevelOperation> {
                 Self::LEVEL_MAX,
                 Self::LEVEL_STEP,
                 T::ENTRIES.len(),
-                Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)),
+                Some(&Into::<Vec<u32>>::into(tlv)),
                 true,
             )
             .map(|_| ())
@@ -172,7 +182,8 @@ pub trait AvcLevelCtlOperation<T: AvcLevelOperation> {
     ) -> Result<bool, Error> {
         if elem_id.get_name().as_str() == Self::LEVEL_NAME {
             ElemValueAccessor::<i32>::set_vals(elem_value, T::ENTRIES.len(), |idx| {
-                T::read_level(avc, idx, timeout_ms).map(|level| level as i32)
+                T::read_level(avc, idx, timeout_ms)
+                    .map(|level| FeatureCtl::volume_to_ctl_value(level, T::LEVEL_MIN))
             })
             .map(|_| true)
         } else {
@@ -190,7 +201,8 @@ pub trait AvcLevelCtlOperation<T: AvcLevelOperation> {
     ) -> Result<bool, Error> {
         if elem_id.get_name().as_str() == Self::LEVEL_NAME {
             ElemValueAccessor::<i32>::get_vals(new, old, T::ENTRIES.len(), |idx, val| {
-                T::write_level(avc, idx, val as i16, timeout_ms)
+                let level = FeatureCtl::volume_from_ctl_value(val, T::LEVEL_MIN);
+                T::write_level(avc, idx, level, timeout_ms)
             })
             .map(|_| true)
         } else {
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2021 Takashi Sakamoto
+
+use glib::Error;
+
+use hinawa::{FwFcpExt, FwReq};
+use hinawa::{SndUnit, SndUnitExt};
+
+use alsactl::{ElemId, ElemIfaceType, ElemValue};
+
+use core::card_cntr::*;
+use core::elem_value_accessor::ElemValueAccessor;
+
+use bebob_protocols::{*, focusrite::*};
+
+use super::common_ctls::*;
+use super::model::CLK_SRC_NAME;
+
+const FCP_TIMEOUT_MS: u32 = 100;
+const TIMEOUT_MS: u32 = 100;
+
+#[derive(Default)]
+pub struct SaffireModel {
+    avc: BebobAvc,
+    req: FwReq,
+    clk_ctl: ClkCtl,
+}
+
+#[derive(Default)]
+struct ClkCtl(Vec<ElemId>);
+
+impl MediaClkFreqCtlOperation<SaffireClkProtocol> for ClkCtl {}
+
+impl ClkCtl {
+    const SRC_LABELS: &'static [&'static str] = &[
+        "Internal",
+        "S/PDIF",
+    ];
+
+    fn load_src(&mut self, card_cntr: &mut CardCntr) -> Result<(), Error> {
+        // NOTE: any transaction to change the source is not known yet.
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, CLK_SRC_NAME, 0);
+        card_cntr.add_enum_elems(&elem_id, 1, 1, Self::SRC_LABELS, None, false)
+            .map(|mut elem_id_list| self.0.append(&mut elem_id_list))
+    }
+
+    fn read_src(&self, unit: &SndUnit, req: &FwReq, elem_id: &ElemId, elem_value: &mut ElemValue)
+        -> Result<bool, Error>
+    {
+        match elem_id.get_name().as_str() {
+            CLK_SRC_NAME => {
+                ElemValueAccessor::<u32>::set_val(elem_value, || {
+                    SaffireClkProtocol::read_clk_src(req, &unit.get_node(), TIMEOUT_MS)
+                        .map(|src| match src {
+                            SaffireClkSrc::Internal => 0,
+                            SaffireClkSrc::Spdif => 1,
+                        })
+                })
+                .map(|_| true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+impl CtlModel<SndUnit> for SaffireModel {
+    fn load(&mut self, unit: &mut SndUnit, card_cntr: &mut CardCntr) -> Result<(), Error> {
+        self.avc.as_ref().bind(&unit.get_node())?;
+
+        self.clk_ctl.load_freq(card_cntr)
+            .map(|mut elem_id_list| self.clk_ctl.0.append(&mut elem_id_list))?;
+
+        self.clk_ctl.load_src(card_cntr)?;
+
+        Ok(())
+    }
+
+    fn read(&mut self, unit: &mut SndUnit, elem_id: &ElemId, elem_value: &mut ElemValue)
+        -> Result<bool, Error>
+    {
+        if self.clk_ctl.read_freq(&self.avc, elem_id, elem_value, FCP_TIMEOUT_MS)? {
+            Ok(true)
+        } else if self.clk_ctl.read_src(unit, &self.req, elem_id, elem_value)? {
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn write(&mut self, unit: &mut SndUnit, elem_id: &ElemId, old: &ElemValue, new: &ElemValue)
+        -> Result<bool, Error>
+    {
+        if self.clk_ctl.write_freq(unit, &self.avc, elem_id, old, new, FCP_TIMEOUT_MS * 3)? {
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+impl NotifyModel<SndUnit, bool> for SaffireModel {
+    fn get_notified_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        elem_id_list.extend_from_slice(&self.clk_ctl.0);
+    }
+
+    fn parse_notification(&mut self, _: &mut SndUnit, _: &bool) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn read_notified_elem(&mut self, unit: &SndUnit, elem_id: &ElemId, elem_value: &mut ElemValue)
+        -> Result<bool, Error>
+    {
+        if self.clk_ctl.read_freq(&self.avc, elem_id, elem_value, FCP_TIMEOUT_MS)? {
+            Ok(true)
+        } else {
+            self.clk_ctl.read_src(unit, &self.req, elem_id, elem_value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alsactl::CardError;
+
+    #[test]
+    fn test_clk_ctl_definition() {
+        let mut card_cntr = CardCntr::new();
+        let mut ctl = ClkCtl::default();
+
+        let error = ctl.load_freq(&mut card_cntr).unwrap_err();
+        assert_eq!(error.kind::<CardError>(), Some(CardError::Failed));
+
+        let error = ctl.load_src(&mut card_cntr).unwrap_err();
+        assert_eq!(error.kind::<CardError>(), Some(CardError::Failed));
+    }
+}
@@ -11,6 +11,7 @@ mod behringer;
 mod digidesign;
 mod stanton;
 mod esi;
+mod focusrite;
 mod icon;
 mod presonus;
 mod roland;
@@ -140,7 +141,7 @@ impl RuntimeOperation<u32> for BebobRuntime {
 
             match ev {
                 Event::Shutdown => break,
-                Event::Disconnected => break,
+                Event::Disconnected => return Err(core::node_disconnected_error()),
                 Event::BusReset(generation) => {
                     println!("IEEE 1394 bus is updated: {}", generation);
                 }
@@ -171,6 +172,10 @@ impl RuntimeOperation<u32> for BebobRuntime {
         }
         Ok(())
     }
+
+    fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error> {
+        Ok(self.card_cntr.get_elem_list())
+    }
 }
 
 impl<'a> BebobRuntime {
@@ -73,6 +73,7 @@ impl<'a> MeterCtl {
     const SWITCH_NAME: &'a str = "switch";
     const SYNC_STATUS_NAME: &'a str = "Sync Status";
     const HP_OUT_METER_NAME: &'a str = "headhpone-meters";
+    const AUX_OUT_METER_NAME: &'a str = "aux-output-meters";
 
     const IN_METER_LABELS: &'a [&'a str] = &[
         "analog-in-1", "analog-in-2", "analog-in-3", "analog-in-4",
@@ -93,12 +94,18 @@ impl<'a> MeterCtl {
         "headphone-out-1", "headphone-out-2", "headphone-out-3", "headphone-out-4",
     ];
 
+    const AUX_OUT_METER_LABELS: &'a [&'a str] = &[
+        "aux-out-1", "aux-out-2",
+    ];
+
     const VAL_MIN: i32 = 0;
     const VAL_MAX: i32 = i16::MAX as i32;
     const VAL_STEP: i32 = 256;
     const VAL_TLV: DbInterval = DbInterval{min: -12800, max: 0, linear: false, mute_avail: false};
 
     const METER_FRAME_SIZE: usize = 84;
+    // NOTE: The first 4 bytes are for the state of switch and rotaries.
+    const METER_OFFSET: usize = 2;
 
     pub fn new() -> Self {
         MeterCtl{
@@ -147,6 +154,7 @@ impl<'a> MeterCtl {
         self.add_meter_elem(card_cntr, IN_METER_NAME, Self::IN_METER_LABELS)?;
         self.add_meter_elem(card_cntr, OUT_METER_NAME, Self::OUT_METER_LABELS)?;
         self.add_meter_elem(card_cntr, Self::HP_OUT_METER_NAME, Self::HP_OUT_METER_LABELS)?;
+        self.add_meter_elem(card_cntr, Self::AUX_OUT_METER_NAME, Self::AUX_OUT_METER_LABELS)?;
 
         self.measure_states(unit, req, avc)?;
 
@@ -225,19 +233,27 @@ impl<'a> MeterCtl {
                 Ok(true)
             }
             IN_METER_NAME => {
-                self.parse_meters(elem_value, 0, Self::IN_METER_LABELS);
+                let offset = Self::METER_OFFSET;
+                self.parse_meters(elem_value, offset, Self::IN_METER_LABELS);
                 Ok(true)
             }
             OUT_METER_NAME => {
-                let offset = Self::IN_METER_LABELS.len();
+                let offset = Self::METER_OFFSET + Self::IN_METER_LABELS.len();
                 self.parse_meters(elem_value, offset, Self::OUT_METER_LABELS);
                 Ok(true)
             }
             Self::HP_OUT_METER_NAME => {
-                let offset = Self::IN_METER_LABELS.len() + Self::OUT_METER_LABELS.len();
+                let offset = Self::METER_OFFSET + Self::IN_METER_LABELS.len() +
+                             Self::OUT_METER_LABELS.len();
                 self.parse_meters(elem_value, offset, Self::HP_OUT_METER_LABELS);
                 Ok(true)
             }
+            Self::AUX_OUT_METER_NAME => {
+                let offset = Self::METER_OFFSET + Self::IN_METER_LABELS.len() +
+                             Self::OUT_METER_LABELS.len() + Self::HP_OUT_METER_LABELS.len();
+                self.parse_meters(elem_value, offset, Self::AUX_OUT_METER_LABELS);
+                Ok(true)
+            }
             _ => Ok(false),
         }
     }
@@ -669,11 +685,11 @@ impl<'a> InputCtl<'a> for StateCache {
                 Ok(true)
             }
             SPDIF_IN_GAIN_NAME => {
-                self.write_input_gain(unit, req, ANALOG_IN_LABELS.len(), SPDIF_IN_GAIN_POS, old, new)?;
+                self.write_input_gain(unit, req, SPDIF_IN_LABELS.len(), SPDIF_IN_GAIN_POS, old, new)?;
                 Ok(true)
             }
             ADAT_IN_GAIN_NAME => {
-                self.write_input_gain(unit, req, ANALOG_IN_LABELS.len(), ADAT_IN_GAIN_POS, old, new)?;
+                self.write_input_gain(unit, req, ADAT_IN_LABELS.len(), ADAT_IN_GAIN_POS, old, new)?;
                 Ok(true)
             }
             ANALOG_IN_PAN_NAME => {
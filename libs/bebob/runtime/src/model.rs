@@ -18,6 +18,7 @@ use super::digidesign::Mbox2proModel;
 use super::stanton::ScratchampModel;
 use super::esi::Quatafire610Model;
 use super::icon::FirexonModel;
+use super::focusrite::SaffireModel;
 use super::presonus::fp10_model::*;
 use super::presonus::firebox_model::*;
 use super::presonus::inspire1394_model::*;
@@ -51,6 +52,7 @@ enum Model {
     RolandFa101(Fa101Model),
     RolandFa66(Fa66Model),
     StantonScratchamp(ScratchampModel),
+    FocusriteSaffire(SaffireModel),
     TerratecAureon(AureonModel),
     TerratecPhase24(GoPhase24CoaxModel),
     TerratecPhaseX24(GoPhase24OptModel),
@@ -59,6 +61,17 @@ enum Model {
     YamahaGo46(GoPhase24OptModel),
 }
 
+// The models known to be based on BeBoB solution, but not supported yet. The tuple consists of
+// vendor ID, model ID, name of model, and module for the vendor.
+const UNSUPPORTED_MODELS: &[(u32, u32, &str, &str)] = &[
+    (0x00130e, 0x000003, "Focusrite Saffire Pro 26 i/o", "focusrite"),
+    (0x00130e, 0x000006, "Focusrite Saffire Pro 10 i/o", "focusrite"),
+    (0x00022e, 0x010067, "TASCAM IF-FW/DM", "tascam"),
+    (0x000d6c, 0x010081, "M-Audio NRV10", "maudio"),
+    (0x0007f5, 0x010048, "BridgeCo RDAudio1", "bridgeco"),
+    (0x0007f5, 0x010049, "BridgeCo Audio5", "bridgeco"),
+];
+
 impl BebobModel {
     pub fn new(vendor_id: u32, model_id: u32) -> Result<Self, Error> {
         let ctl_model = match (vendor_id, model_id) {
@@ -80,6 +93,7 @@ impl BebobModel {
             (0x0040ab, 0x010048) => Model::RolandFa101(Default::default()),
             (0x0040ab, 0x010049) => Model::RolandFa66(Default::default()),
             (0x001260, 0x000001) => Model::StantonScratchamp(Default::default()),
+            (0x00130e, 0x000000) => Model::FocusriteSaffire(Default::default()),
             (0x000aac, 0x000002) => Model::TerratecAureon(Default::default()),
             (0x000aac, 0x000004) => Model::TerratecPhase24(Default::default()),
             (0x000aac, 0x000007) => Model::TerratecPhaseX24(Default::default()),
@@ -87,7 +101,13 @@ impl BebobModel {
             (0x00a0de, 0x10000b) => Model::YamahaGo44(Default::default()),
             (0x00a0de, 0x10000c) => Model::YamahaGo46(Default::default()),
             _ => {
-                return Err(Error::new(FileError::Noent, "Not supported"));
+                let msg = UNSUPPORTED_MODELS.iter()
+                    .find(|&&(v, m, _, _)| v == vendor_id && m == model_id)
+                    .map(|&(_, _, name, vendor)| {
+                        format!("{} is not supported yet, to be implemented in {} module", name, vendor)
+                    })
+                    .unwrap_or_else(|| "Not supported".to_string());
+                return Err(Error::new(FileError::Noent, &msg));
             }
         };
 
@@ -122,6 +142,7 @@ impl BebobModel {
             Model::RolandFa101(m) => m.load(unit, card_cntr),
             Model::RolandFa66(m) => m.load(unit, card_cntr),
             Model::StantonScratchamp(m) => m.load(unit, card_cntr),
+            Model::FocusriteSaffire(m) => m.load(unit, card_cntr),
             Model::TerratecAureon(m) => m.load(unit, card_cntr),
             Model::TerratecPhase24(m) => m.load(unit, card_cntr),
             Model::TerratecPhaseX24(m) => m.load(unit, card_cntr),
@@ -159,6 +180,7 @@ impl BebobModel {
             Model::PresonusFirebox(m) => m.get_notified_elem_list(&mut self.notified_elem_list),
             Model::PresonusInspire1394(m) => m.get_notified_elem_list(&mut self.notified_elem_list),
             Model::StantonScratchamp(m) => m.get_notified_elem_list(&mut self.notified_elem_list),
+            Model::FocusriteSaffire(m) => m.get_notified_elem_list(&mut self.notified_elem_list),
             Model::TerratecAureon(m) => m.get_notified_elem_list(&mut self.notified_elem_list),
             Model::TerratecPhase88(m) => m.get_notified_elem_list(&mut self.notified_elem_list),
             Model::TerratecPhase24(m) => m.get_notified_elem_list(&mut self.notified_elem_list),
@@ -194,6 +216,7 @@ impl BebobModel {
             Model::RolandFa101(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
             Model::RolandFa66(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
             Model::StantonScratchamp(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
+            Model::FocusriteSaffire(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
             Model::TerratecAureon(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
             Model::TerratecPhase24(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
             Model::TerratecPhaseX24(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
@@ -239,6 +262,7 @@ impl BebobModel {
             Model::PresonusFirebox(m) => card_cntr.dispatch_notification(unit, &notice, &self.notified_elem_list, m),
             Model::PresonusInspire1394(m) => card_cntr.dispatch_notification(unit, &notice, &self.notified_elem_list, m),
             Model::StantonScratchamp(m) => card_cntr.dispatch_notification(unit, &notice, &self.notified_elem_list, m),
+            Model::FocusriteSaffire(m) => card_cntr.dispatch_notification(unit, &notice, &self.notified_elem_list, m),
             Model::TerratecAureon(m) => card_cntr.dispatch_notification(unit, &notice, &self.notified_elem_list, m),
             Model::TerratecPhase88(m) => card_cntr.dispatch_notification(unit, &notice, &self.notified_elem_list, m),
             Model::TerratecPhase24(m) => card_cntr.dispatch_notification(unit, &notice, &self.notified_elem_list, m),
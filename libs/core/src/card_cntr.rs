@@ -7,6 +7,8 @@ use glib::{Error, FileError};
 pub struct CardCntr {
     pub card: alsactl::Card,
     entries: Vec<alsactl::ElemValue>,
+    elem_list: Vec<(alsactl::ElemId, alsactl::ElemType)>,
+    without_card: bool,
 }
 
 pub trait CtlModel<O: IsA<hinawa::SndUnit>> {
@@ -24,6 +26,10 @@ pub trait CtlModel<O: IsA<hinawa::SndUnit>> {
         old: &alsactl::ElemValue,
         new: &alsactl::ElemValue,
     ) -> Result<bool, Error>;
+
+    /// Retrieve the list of elements of which value is changed as a side effect of the last write
+    /// operation, so that the elements are updated.
+    fn get_changed_elem_list(&mut self, _elem_id_list: &mut Vec<alsactl::ElemId>) {}
 }
 
 pub trait MeasureModel<O: IsA<hinawa::SndUnit>> {
@@ -55,6 +61,19 @@ impl CardCntr {
         CardCntr {
             card: alsactl::Card::new(),
             entries: Vec::new(),
+            elem_list: Vec::new(),
+            without_card: false,
+        }
+    }
+
+    /// Instantiate without sound card, to retrieve the list of elements the model adds in load
+    /// when the device is not present.
+    pub fn new_without_card() -> Self {
+        CardCntr {
+            card: alsactl::Card::new(),
+            entries: Vec::new(),
+            elem_list: Vec::new(),
+            without_card: true,
         }
     }
 
@@ -169,6 +188,11 @@ impl CardCntr {
         Ok(elem_id_list.remove(0))
     }
 
+    /// Retrieve the list of identifier and type for elements added by the runtime.
+    pub fn get_elem_list(&self) -> Vec<(alsactl::ElemId, alsactl::ElemType)> {
+        self.elem_list.clone()
+    }
+
     fn register_elems<P>(
         &mut self,
         elem_id: &alsactl::ElemId,
@@ -180,6 +204,21 @@ impl CardCntr {
     where
         P: IsA<alsactl::ElemInfo>,
     {
+        let elem_type = elem_info.get_property_type();
+
+        if self.without_card {
+            let elem_id_list: Vec<alsactl::ElemId> = (0..elem_count as u32)
+                .map(|i| {
+                    alsactl::ElemId::new_by_name(elem_id.get_iface(), elem_id.get_device_id(),
+                                                 elem_id.get_subdevice_id(),
+                                                 elem_id.get_name().as_str(),
+                                                 elem_id.get_index() + i)
+                })
+                .collect();
+            self.elem_list.extend(elem_id_list.iter().map(|elem_id| (elem_id.clone(), elem_type)));
+            return Ok(elem_id_list);
+        }
+
         // If already registered, reuse them if possible.
         let elem_id_list = self.card.get_elem_id_list()?;
         let elem_id_list = match elem_id_list.iter().position(|eid| eid.eq(elem_id)) {
@@ -274,6 +313,8 @@ impl CardCntr {
             });
         }
 
+        self.elem_list.extend(elem_id_list.iter().map(|elem_id| (elem_id.clone(), elem_type)));
+
         Ok(elem_id_list)
     }
 
@@ -328,6 +369,8 @@ impl CardCntr {
         }
 
         if events.contains(alsactl::ElemEventMask::VALUE) {
+            let mut written = false;
+
             for v in &mut self.entries {
                 let e = match v.get_property_elem_id() {
                     Some(e) => e,
@@ -352,7 +395,8 @@ impl CardCntr {
                     Ok(res) => {
                         if res {
                             *v = val;
-                            return Ok(());
+                            written = true;
+                            break;
                         }
                     }
                     Err(err) => {
@@ -362,11 +406,47 @@ impl CardCntr {
                     }
                 }
             }
+
+            if written {
+                let mut elem_id_list = Vec::new();
+                ctl_model.get_changed_elem_list(&mut elem_id_list);
+                self.update_elems(unit, &elem_id_list, ctl_model)?;
+            }
         }
 
         Ok(())
     }
 
+    fn update_elems<O, T>(
+        &mut self,
+        unit: &mut O,
+        elem_id_list: &[alsactl::ElemId],
+        ctl_model: &mut T,
+    ) -> Result<(), Error>
+    where
+        O: IsA<hinawa::SndUnit>,
+        T: CtlModel<O>,
+    {
+        let card = &self.card;
+        let entries = &mut self.entries;
+
+        elem_id_list.iter().try_for_each(|elem_id| {
+            entries.iter_mut().filter(|elem_value| {
+                match elem_value.get_property_elem_id() {
+                    Some(eid) => eid == *elem_id,
+                    None => false,
+                }
+            }).try_for_each(|elem_value| {
+                let mut val = alsactl::ElemValue::new();
+                if ctl_model.read(unit, elem_id, &mut val)? {
+                    write_elem_value_if_changed(card, elem_id, elem_value, val)?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
     pub fn measure_elems<O, T>(
         &mut self,
         unit: &mut O,
@@ -389,8 +469,9 @@ impl CardCntr {
                     None => false,
                 }
             }).try_for_each(|elem_value| {
-                if ctl_model.measure_elem(unit, elem_id, elem_value)? {
-                    card.write_elem_value(elem_id, elem_value)?;
+                let mut val = alsactl::ElemValue::new();
+                if ctl_model.measure_elem(unit, elem_id, &mut val)? {
+                    write_elem_value_if_changed(card, elem_id, elem_value, val)?;
                 }
 
                 Ok(())
@@ -421,8 +502,9 @@ impl CardCntr {
                     None => false,
                 }
             }).try_for_each(|elem_value| {
-                if ctl_model.read_notified_elem(unit, elem_id, elem_value)? {
-                    card.write_elem_value(elem_id, elem_value)?;
+                let mut val = alsactl::ElemValue::new();
+                if ctl_model.read_notified_elem(unit, elem_id, &mut val)? {
+                    write_elem_value_if_changed(card, elem_id, elem_value, val)?;
                 }
 
                 Ok(())
@@ -430,3 +512,19 @@ impl CardCntr {
         })
     }
 }
+
+// The event of value change is emitted to clients every time the value is written to the element,
+// thus the write is skipped when the value is not changed, to suppress the storm of events when
+// many elements are refreshed at once.
+fn write_elem_value_if_changed(
+    card: &alsactl::Card,
+    elem_id: &alsactl::ElemId,
+    old: &mut alsactl::ElemValue,
+    new: alsactl::ElemValue,
+) -> Result<(), Error> {
+    if !old.equal(&new) {
+        card.write_elem_value(elem_id, &new)?;
+        *old = new;
+    }
+    Ok(())
+}
@@ -4,10 +4,25 @@ pub mod dispatcher;
 pub mod card_cntr;
 pub mod elem_value_accessor;
 
-use glib::Error;
+use glib::{Error, FileError};
+
+use hinawa::FwNodeError;
 
 pub trait RuntimeOperation<T> : Sized {
     fn new(arg: T) -> Result<Self, Error>;
     fn listen(&mut self) -> Result<(), Error>;
     fn run(&mut self) -> Result<(), Error>;
+    /// Retrieve the list of identifier and type for ALSA control elements added by the runtime.
+    fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error>;
+
+    /// Print raw content of registers in the unit for support. The operation should be read-only.
+    fn dump(&mut self) -> Result<(), Error> {
+        Err(Error::new(FileError::Nosys, "Dump of registers is not supported by the runtime"))
+    }
+}
+
+/// Generate error to notify that the node for the unit disappears from IEEE 1394 bus. The service
+/// program can attempt to open the unit again when receiving the error from run loop.
+pub fn node_disconnected_error() -> Error {
+    Error::new(FwNodeError::Disconnected, "The node for the unit is disconnected")
 }
@@ -31,15 +31,15 @@ pub struct Pfire2626State(Tcd22xxState);
 
 impl Tcd22xxSpec for Pfire2626State {
     const INPUTS: &'static [Input] = &[
-        Input{id: SrcBlkId::Ins1, offset: 0, count: 8, label: None},
-        Input{id: SrcBlkId::Adat, offset: 0, count: 8, label: None},
-        Input{id: SrcBlkId::Adat, offset: 8, count: 8, label: None},
+        Input{id: SrcBlkId::Ins1, offset: 0, count: 8, label: Some("Analog")},
+        Input{id: SrcBlkId::Adat, offset: 0, count: 8, label: Some("ADAT-A")},
+        Input{id: SrcBlkId::Adat, offset: 8, count: 8, label: Some("ADAT-B")},
         Input{id: SrcBlkId::Aes, offset: 0, count: 2, label: None},
     ];
     const OUTPUTS: &'static [Output] = &[
-        Output{id: DstBlkId::Ins1, offset: 0, count: 8, label: None},
-        Output{id: DstBlkId::Adat, offset: 0, count: 8, label: None},
-        Output{id: DstBlkId::Adat, offset: 8, count: 8, label: None},
+        Output{id: DstBlkId::Ins1, offset: 0, count: 8, label: Some("Analog")},
+        Output{id: DstBlkId::Adat, offset: 0, count: 8, label: Some("ADAT-A")},
+        Output{id: DstBlkId::Adat, offset: 8, count: 8, label: Some("ADAT-B")},
         Output{id: DstBlkId::Aes, offset: 0, count: 2, label: None},
     ];
     const FIXED: &'static [SrcBlk] = &[
@@ -84,17 +84,19 @@ pub struct Pfire610State(Tcd22xxState);
 // NOTE: the second rx stream is firstly available at higher sampling rate.
 impl Tcd22xxSpec for Pfire610State {
     const INPUTS: &'static [Input] = &[
-        Input{id: SrcBlkId::Ins0, offset: 0, count: 4, label: None},
+        Input{id: SrcBlkId::Ins0, offset: 0, count: 4, label: Some("Analog")},
         Input{id: SrcBlkId::Aes,  offset: 0, count: 2, label: None},
     ];
     const OUTPUTS: &'static [Output] = &[
-        Output{id: DstBlkId::Ins0, offset: 0, count: 8, label: None},
+        Output{id: DstBlkId::Ins0, offset: 0, count: 8, label: Some("Analog")},
         Output{id: DstBlkId::Aes,  offset: 0, count: 2, label: None},
     ];
     const FIXED: &'static [SrcBlk] = &[
         SrcBlk{id: SrcBlkId::Ins0, ch: 0},
         SrcBlk{id: SrcBlkId::Ins0, ch: 1},
     ];
+
+    const RX_STREAM_COUNTS: [usize;3] = [1, 2, 2];
 }
 
 impl AsRef<Tcd22xxState> for Pfire610State {
@@ -245,3 +247,61 @@ pub trait MaudioPfireApplProtocol<T> : ApplSectionProtocol<T>
 }
 
 impl<O: AsRef<FwReq>, T: AsRef<FwNode>> MaudioPfireApplProtocol<T> for O {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tcat::extension::cmd_section::RateMode;
+
+    #[test]
+    fn pfire_clock_rates_test() {
+        let rates = [
+            ClockRate::R32000, ClockRate::R44100, ClockRate::R48000,
+            ClockRate::R88200, ClockRate::R96000,
+            ClockRate::R176400, ClockRate::R192000,
+        ];
+
+        let caps = ClockCaps::new(&Pfire2626State::AVAIL_CLK_RATES, Pfire2626State::AVAIL_CLK_SRCS);
+        assert_eq!(caps.get_rate_entries(), rates);
+
+        let caps = ClockCaps::new(&Pfire610State::AVAIL_CLK_RATES, Pfire610State::AVAIL_CLK_SRCS);
+        assert_eq!(caps.get_rate_entries(), rates);
+    }
+
+    #[test]
+    fn pfire2626_fixed_router_entries_test() {
+        let state = Pfire2626State::default();
+        let (srcs, dsts) = state.compute_avail_real_blk_pair(RateMode::Low);
+        let srcs: Vec<&SrcBlk> = srcs.iter().collect();
+        let dsts: Vec<&DstBlk> = dsts.iter().collect();
+
+        // The fixed source is not connected to any destination.
+        let adat_src = SrcBlk{id: SrcBlkId::Adat, ch: 0};
+        let ins_dst = DstBlk{id: DstBlkId::Ins1, ch: 0};
+        let entries = vec![RouterEntry{dst: ins_dst, src: adat_src, ..Default::default()}];
+        let entries = state.refine_router_entries(entries, &srcs, &dsts);
+        assert_eq!(entries.len(), Pfire2626State::FIXED.len() + 1);
+        Pfire2626State::FIXED.iter()
+            .zip(entries.iter())
+            .for_each(|(src, entry)| {
+                assert_eq!(&entry.src, src);
+                assert_eq!(entry.dst, DstBlk{id: DstBlkId::Reserved(0xff), ch: 0xff});
+            });
+        assert_eq!(entries[Pfire2626State::FIXED.len()].src, adat_src);
+        assert_eq!(entries[Pfire2626State::FIXED.len()].dst, ins_dst);
+
+        // The fixed source is connected to destination.
+        let fixed_src = Pfire2626State::FIXED[3];
+        let entries = vec![
+            RouterEntry{dst: ins_dst, src: adat_src, ..Default::default()},
+            RouterEntry{dst: DstBlk{id: DstBlkId::Aes, ch: 1}, src: fixed_src, ..Default::default()},
+        ];
+        let entries = state.refine_router_entries(entries, &srcs, &dsts);
+        assert_eq!(entries.len(), Pfire2626State::FIXED.len() + 1);
+        Pfire2626State::FIXED.iter()
+            .zip(entries.iter())
+            .for_each(|(src, entry)| assert_eq!(&entry.src, src));
+        assert_eq!(entries[3].dst, DstBlk{id: DstBlkId::Aes, ch: 1});
+        assert!(entries.iter().any(|entry| entry.src == adat_src && entry.dst == ins_dst));
+    }
+}
@@ -14,6 +14,9 @@
 //! used for supertrait. Any call of method in the trait initiates asynchronous transaction to
 //! operate the registers.
 //!
+//! When the crate is built with `trace-transaction` feature, each of the transactions is printed to
+//! standard error with prefix `dice-transaction:`, for reverse-engineering of unknown registers.
+//!
 //! ## Utilities
 //!
 //! Some programs are available under 'src/bin' directory.
@@ -106,13 +109,14 @@ pub mod tcd22xx_spec;
 
 pub mod config_rom;
 
-use glib::{Error, error::ErrorDomain, Quark};
+use glib::{Error, FileError, error::ErrorDomain, Quark};
 
 use hinawa::{FwNode, FwTcode, FwReq, FwReqExtManual};
 
 mod utils;
 
 use std::ops::BitAnd;
+use std::time::{Duration, Instant};
 
 /// The structure to represent section in control and status register (CSR) of node.
 #[derive(Default, Clone, Copy, Debug, Eq, PartialEq)]
@@ -172,6 +176,7 @@ pub enum GeneralProtocolError {
     Global,
     TxStreamFormat,
     RxStreamFormat,
+    Timeout,
     Invalid(i32),
 }
 
@@ -181,6 +186,7 @@ impl std::fmt::Display for GeneralProtocolError {
             GeneralProtocolError::Global => "global",
             GeneralProtocolError::TxStreamFormat => "tx-stream-format",
             GeneralProtocolError::RxStreamFormat => "rx-stream-format",
+            GeneralProtocolError::Timeout => "timeout",
             GeneralProtocolError::Invalid(_) => "invalid",
         };
 
@@ -198,6 +204,7 @@ impl ErrorDomain for GeneralProtocolError {
             GeneralProtocolError::Global => 0,
             GeneralProtocolError::TxStreamFormat => 1,
             GeneralProtocolError::RxStreamFormat => 2,
+            GeneralProtocolError::Timeout => 3,
             GeneralProtocolError::Invalid(v) => v,
         }
     }
@@ -207,12 +214,55 @@ impl ErrorDomain for GeneralProtocolError {
             0 => GeneralProtocolError::Global,
             1 => GeneralProtocolError::TxStreamFormat,
             2 => GeneralProtocolError::RxStreamFormat,
+            3 => GeneralProtocolError::Timeout,
             _ => GeneralProtocolError::Invalid(code),
         };
         Some(enumeration)
     }
 }
 
+const TRACE_TRANSACTION: bool = cfg!(feature = "trace-transaction");
+
+// Print the transaction to stderr when the crate is built with `trace-transaction` feature, for
+// reverse-engineering of registers. The call is optimized out without the feature.
+fn trace_transaction(tcode: FwTcode, addr: u64, frames: &[u8], res: &Result<(), Error>) {
+    if TRACE_TRANSACTION {
+        let bytes = frames.iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<String>>()
+            .join(" ");
+        match res {
+            Ok(_) => eprintln!("dice-transaction: {:?} 0x{:012x} {}: {}", tcode, addr, frames.len(), bytes),
+            Err(e) => eprintln!("dice-transaction: {:?} 0x{:012x} {}: {}", tcode, addr, frames.len(), e),
+        }
+    }
+}
+
+// NOTE: hinawa reports ETIMEDOUT of transaction in generic code of file error domain. The failure
+// is distinguished from the others by the time elapsed in the transaction.
+fn check_transaction_timeout(res: Result<(), Error>, start: Instant, timeout_ms: u32)
+    -> Result<(), Error>
+{
+    res.map_err(|e| {
+        if e.kind::<FileError>() == Some(FileError::Failed) &&
+           start.elapsed() >= Duration::from_millis(timeout_ms as u64) {
+            Error::new(GeneralProtocolError::Timeout, &e.to_string())
+        } else {
+            e
+        }
+    })
+}
+
+// Wrap the error into the cause in the domain of section, except for timeout which callers need
+// to distinguish.
+fn section_error<E: ErrorDomain>(e: Error, cause: E) -> Error {
+    if e.kind::<GeneralProtocolError>() == Some(GeneralProtocolError::Timeout) {
+        e
+    } else {
+        Error::new(cause, &e.to_string())
+    }
+}
+
 /// The trait for general protocol.
 pub trait GeneralProtocol<T: AsRef<FwNode>> : AsRef<FwReq> {
     const BASE_ADDR: u64 = 0xffffe0000000;
@@ -231,7 +281,11 @@ pub trait GeneralProtocol<T: AsRef<FwNode>> : AsRef<FwReq> {
                 FwTcode::ReadBlockRequest
             };
 
-            self.as_ref().transaction_sync(node.as_ref(), tcode, addr, len, &mut frames[0..len], timeout_ms)?;
+            let start = Instant::now();
+            let res = self.as_ref().transaction_sync(node.as_ref(), tcode, addr, len, &mut frames[0..len],
+                                                     timeout_ms);
+            trace_transaction(tcode, addr, &frames[0..len], &res);
+            check_transaction_timeout(res, start, timeout_ms)?;
 
             addr += len as u64;
             frames = &mut frames[len..];
@@ -254,7 +308,10 @@ pub trait GeneralProtocol<T: AsRef<FwNode>> : AsRef<FwReq> {
                 FwTcode::WriteBlockRequest
             };
 
-            self.as_ref().transaction_sync(n, tcode, addr, len, &mut frames[0..len], timeout_ms)?;
+            let start = Instant::now();
+            let res = self.as_ref().transaction_sync(n, tcode, addr, len, &mut frames[0..len], timeout_ms);
+            trace_transaction(tcode, addr, &frames[0..len], &res);
+            check_transaction_timeout(res, start, timeout_ms)?;
 
             addr += len as u64;
             frames = &mut frames[len..];
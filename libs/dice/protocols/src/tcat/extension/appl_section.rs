@@ -16,7 +16,7 @@ pub trait ApplSectionProtocol<T> : ProtocolExtension<T>
         -> Result<(), Error>
     {
         ProtocolExtension::read(self, node, sections.application.offset + offset, frames, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Appl, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Appl))
     }
 
     fn write_appl_data(&self, node: &T, sections: &ExtensionSections, offset: usize,
@@ -24,7 +24,7 @@ pub trait ApplSectionProtocol<T> : ProtocolExtension<T>
         -> Result<(), Error>
     {
         ProtocolExtension::write(self, node, sections.application.offset + offset, frames, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Appl, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Appl))
     }
 }
 
@@ -198,7 +198,7 @@ pub trait CapsSectionProtocol<T> : ProtocolExtension<T>
     {
         let mut data = [0;ExtensionCaps::SIZE];
         ProtocolExtension::read(self, node, sections.caps.offset, &mut data, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Caps, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Caps))
             .map(|_| ExtensionCaps::from(&data[..]))
     }
 }
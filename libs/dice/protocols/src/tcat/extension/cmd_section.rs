@@ -136,19 +136,19 @@ pub trait CmdSectionProtocol<T> : ProtocolExtension<T>
         data[0] = Self::EXECUTE;
         ProtocolExtension::write(self, node, sections.cmd.offset + Self::OPCODE_OFFSET, &mut data,
                                  timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Cmd, &e.to_string()))?;
+            .map_err(|e| section_error(e, ProtocolExtensionError::Cmd))?;
 
         let mut count = 0;
         while count < 10 {
             std::thread::sleep(std::time::Duration::from_millis(50));
 
             ProtocolExtension::read(self, node, sections.cmd.offset, &mut data, timeout_ms)
-                .map_err(|e| Error::new(ProtocolExtensionError::Cmd, &e.to_string()))?;
+                .map_err(|e| section_error(e, ProtocolExtensionError::Cmd))?;
 
             if (data[0] & Self::EXECUTE) != Self::EXECUTE {
                 ProtocolExtension::read(self, node, sections.cmd.offset + Self::RETURN_OFFSET, &mut data,
                                         timeout_ms)
-                    .map_err(|e| Error::new(ProtocolExtensionError::Cmd, &e.to_string()))?;
+                    .map_err(|e| section_error(e, ProtocolExtensionError::Cmd))?;
                 return Ok(u32::from_be_bytes(data));
             }
             count += 1;
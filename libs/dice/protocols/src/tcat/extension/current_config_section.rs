@@ -33,13 +33,13 @@ pub trait CurrentConfigSectionProtocol<T> : ProtocolExtension<T>
         let mut data = [0;4];
         let offset = sections.current_config.offset + offset;
         ProtocolExtension::read(self, node, offset, &mut data, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::CurrentConfig, &e.to_string()))?;
+            .map_err(|e| section_error(e, ProtocolExtensionError::CurrentConfig))?;
 
         let entry_count = std::cmp::min(u32::from_be_bytes(data) as usize,
                                         caps.router.maximum_entry_count as usize);
 
         RouterEntryProtocol::read_router_entries(&self, node, caps, offset + 4, entry_count, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::CurrentConfig, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::CurrentConfig))
     }
 
     fn read_current_stream_format_entries(&self, node: &T, sections: &ExtensionSections, caps: &ExtensionCaps,
@@ -53,7 +53,7 @@ pub trait CurrentConfigSectionProtocol<T> : ProtocolExtension<T>
         };
         let offset = sections.current_config.offset + offset;
         StreamFormatEntryProtocol::read_stream_format_entries(&self, node, caps, offset, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::CurrentConfig, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::CurrentConfig))
     }
 }
 
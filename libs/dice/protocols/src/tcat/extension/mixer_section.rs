@@ -23,7 +23,7 @@ pub trait MixerSectionProtocol<T> : ProtocolExtension<T>
         let mut data = [0;4];
         ProtocolExtension::read(self, node, sections.mixer.offset + Self::SATURATION_OFFSET, &mut data,
                                 timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Mixer, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Mixer))
             .map(|_| {
                 let val = u32::from_be_bytes(data);
                 (0..caps.mixer.output_count)
@@ -44,7 +44,7 @@ pub trait MixerSectionProtocol<T> : ProtocolExtension<T>
         let mut data = [0;4];
         ProtocolExtension::read(self, node, sections.mixer.offset + Self::COEFF_OFFSET + offset,
                                 &mut data, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Mixer, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Mixer))
             .map(|_|  u32::from_be_bytes(data))
     }
 
@@ -61,7 +61,7 @@ pub trait MixerSectionProtocol<T> : ProtocolExtension<T>
         data.copy_from_slice(&val.to_be_bytes());
         ProtocolExtension::write(self, node, sections.mixer.offset + Self::COEFF_OFFSET + offset,
                                  &mut data, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Mixer, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Mixer))
     }
 }
 
@@ -21,7 +21,7 @@ pub trait PeakSectionProtocol<T> : ProtocolExtension<T>
         let entries = caps.router.maximum_entry_count as usize;
         RouterEntryProtocol::read_router_entries(&self, node, caps, sections.peak.offset, entries,
                                                  timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Peak, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Peak))
     }
 }
 
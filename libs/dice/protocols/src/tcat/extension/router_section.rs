@@ -17,13 +17,13 @@ pub trait RouterSectionProtocol<T> : ProtocolExtension<T>
     {
         let mut data = [0;4];
         ProtocolExtension::read(self, node, sections.router.offset, &mut data, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Router, &e.to_string()))?;
+            .map_err(|e| section_error(e, ProtocolExtensionError::Router))?;
 
         let entry_count = std::cmp::min(u32::from_be_bytes(data) as usize,
                                         caps.router.maximum_entry_count as usize);
         RouterEntryProtocol::read_router_entries(&self, node, caps, sections.router.offset + 4,
                                                  entry_count, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Router, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Router))
     }
 
     fn write_router_entries(&self, node: &T, sections: &ExtensionSections, caps: &ExtensionCaps,
@@ -32,7 +32,7 @@ pub trait RouterSectionProtocol<T> : ProtocolExtension<T>
     {
         RouterEntryProtocol::write_router_entries(&self, node, caps, sections.router.offset,
                                                   entries, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Router, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Router))
     }
 }
 
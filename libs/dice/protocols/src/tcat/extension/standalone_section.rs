@@ -152,7 +152,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
         let mut quadlet = [0;4];
         ProtocolExtension::read(self, node, sections.standalone.offset + Self::CLK_SRC_OFFSET,
                                 &mut quadlet, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
             .map(|_| ClockSource::from(u32::from_be_bytes(quadlet) as u8))
     }
 
@@ -163,7 +163,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
         quadlet.copy_from_slice(&(u8::from(src) as u32).to_be_bytes());
         ProtocolExtension::write(self, node, sections.standalone.offset + Self::CLK_SRC_OFFSET,
                                  &mut quadlet, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
     }
 
     fn read_standalone_aes_high_rate(&self, node: &T, sections: &ExtensionSections, timeout_ms: u32)
@@ -172,7 +172,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
         let mut quadlet = [0;4];
         ProtocolExtension::read(self, node, sections.standalone.offset + Self::AES_CFG_OFFSET,
                                 &mut quadlet, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
             .map(|_| u32::from_be_bytes(quadlet) > 0)
     }
 
@@ -183,7 +183,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
         quadlet.copy_from_slice(&(enable as u32).to_be_bytes());
         ProtocolExtension::write(self, node, sections.standalone.offset + Self::AES_CFG_OFFSET,
                                  &mut quadlet, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
     }
 
     fn read_standalone_adat_mode(&self, node: &T, sections: &ExtensionSections, timeout_ms: u32)
@@ -192,7 +192,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
         let mut quadlet = [0;4];
         ProtocolExtension::read(self, node, sections.standalone.offset + Self::ADAT_CFG_OFFSET,
                                 &mut quadlet, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
             .map(|_| AdatParam::from(quadlet))
     }
 
@@ -201,7 +201,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
     {
         ProtocolExtension::write(self, node, sections.standalone.offset + Self::ADAT_CFG_OFFSET,
                                  &mut Into::<[u8;4]>::into(param), timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
     }
 
     fn read_standalone_word_clock_param(&self, node: &T, sections: &ExtensionSections, timeout_ms: u32)
@@ -210,7 +210,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
         let mut quadlet = [0;4];
         ProtocolExtension::read(self, node, sections.standalone.offset + Self::WC_CFG_OFFSET,
                                 &mut quadlet, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
             .map(|_| WordClockParam::from(quadlet))
     }
 
@@ -222,7 +222,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
         quadlet.copy_from_slice(&Into::<[u8;4]>::into(param)[..4]);
         ProtocolExtension::write(self, node, sections.standalone.offset + Self::WC_CFG_OFFSET,
                                  &mut quadlet, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
     }
 
     fn read_standalone_internal_rate(&self, node: &T, sections: &ExtensionSections, timeout_ms: u32)
@@ -231,7 +231,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
         let mut quadlet = [0;4];
         ProtocolExtension::read(self, node, sections.standalone.offset + Self::INTERNAL_CFG_OFFSET,
                                 &mut quadlet, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
             .map(|_| ClockRate::from(u32::from_be_bytes(quadlet) as u8))
     }
 
@@ -242,7 +242,7 @@ pub trait StandaloneSectionProtocol<T> : ProtocolExtension<T>
         quadlet.copy_from_slice(&(u8::from(rate) as u32).to_be_bytes());
         ProtocolExtension::write(self, node, sections.standalone.offset + Self::INTERNAL_CFG_OFFSET,
                                  &mut quadlet, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::Standalone, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::Standalone))
     }
 }
 
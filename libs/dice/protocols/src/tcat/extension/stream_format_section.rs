@@ -17,7 +17,7 @@ pub trait StreamFormatSectionProtocol<T> : ProtocolExtension<T>
     {
         StreamFormatEntryProtocol::read_stream_format_entries(&self, node, caps, sections.stream_format.offset,
                                                               timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::StreamFormat, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::StreamFormat))
     }
 
     fn write_stream_format_entries(&self, node: &T, sections: &ExtensionSections, caps: &ExtensionCaps,
@@ -26,7 +26,7 @@ pub trait StreamFormatSectionProtocol<T> : ProtocolExtension<T>
     {
         StreamFormatEntryProtocol::write_stream_format_entries(&self, node, caps, sections.stream_format.offset,
                                                                pair, timeout_ms)
-            .map_err(|e| Error::new(ProtocolExtensionError::StreamFormat, &e.to_string()))
+            .map_err(|e| section_error(e, ProtocolExtensionError::StreamFormat))
     }
 }
 
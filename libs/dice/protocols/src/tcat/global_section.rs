@@ -464,6 +464,18 @@ pub struct ClockSourceLabels{
     pub entries: Vec<String>,
 }
 
+impl ClockSourceLabels {
+    /// The maximum size of all labels in bytes.
+    pub const MAX_SIZE: usize = 256;
+
+    /// Whether the labels can be stored in the global section. Each label is terminated by
+    /// backslash, and the list of labels is terminated by double backslashes.
+    pub fn is_storable(&self) -> bool {
+        let size = self.entries.iter().fold(1, |size, label| size + label.len() + 1);
+        size <= Self::MAX_SIZE && self.entries.iter().all(|label| label.find('\\').is_none())
+    }
+}
+
 /// The maximum size of nickname in bytes.
 pub const NICKNAME_MAX_SIZE: usize = 64;
 
@@ -483,14 +495,14 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     const CLK_CAPS_OFFSET: usize = 0x64;
     const CLK_NAMES_OFFSET: usize = 0x68;
 
-    const CLK_NAMES_MAX_SIZE: usize = 256;
+    const CLK_NAMES_MAX_SIZE: usize = ClockSourceLabels::MAX_SIZE;
 
     fn read_owner_addr(&self, node: &T, sections: &GeneralSections, timeout_ms: u32)
         -> Result<u64, Error>
     {
         let mut data = [0;8];
         self.read(node, sections.global.offset + Self::OWNER_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
             .map(|_| {
                 let mut quadlet = [0;4];
                 quadlet.copy_from_slice(&data[..4]);
@@ -508,7 +520,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;4];
         self.read(node, sections.global.offset + Self::LATEST_NOTIFICATION_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
             .map(|_| u32::from_be_bytes(data))
     }
 
@@ -518,7 +530,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = build_label(name, NICKNAME_MAX_SIZE);
         self.write(node, sections.global.offset + Self::NICKNAME_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
     }
 
     fn read_nickname(&self, node: &T, sections: &GeneralSections, timeout_ms: u32)
@@ -526,7 +538,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = vec![0;NICKNAME_MAX_SIZE];
         self.read(node, sections.global.offset + Self::NICKNAME_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
             .and_then(|_| {
                 parse_label(&data[..])
                     .map_err(|e| {
@@ -544,7 +556,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
         let val = u32::from(config);
         data.copy_from_slice(&val.to_be_bytes());
         self.write(node, sections.global.offset + Self::CLK_SELECT_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
     }
 
     fn read_clock_config(&self, node: &T, sections: &GeneralSections, timeout_ms: u32)
@@ -552,7 +564,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;4];
         self.read(node, sections.global.offset + Self::CLK_SELECT_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
             .map(|_| ClockConfig::from(u32::from_be_bytes(data)))
     }
 
@@ -561,7 +573,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;4];
         self.read(node, sections.global.offset + Self::ENABLED_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
             .map(|_| u32::from_be_bytes(data) > 0)
     }
 
@@ -570,7 +582,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;4];
         self.read(node, sections.global.offset + Self::STATUS_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
             .map(|_| ClockStatus::from(u32::from_be_bytes(data)))
     }
 
@@ -579,7 +591,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;4];
         self.read(node, sections.global.offset + Self::CLK_SRC_STATES_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
             .map(|_| ExtSourceStates::from(u32::from_be_bytes(data)))
     }
 
@@ -588,7 +600,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;4];
         self.read(node, sections.global.offset + Self::CURRENT_RATE_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
             .map(|_| u32::from_be_bytes(data))
     }
 
@@ -597,7 +609,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;4];
         self.read(node, sections.global.offset + Self::VERSION_OFFSET, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+            .map_err(|e| section_error(e, GeneralProtocolError::Global))
             .map(|_| u32::from_be_bytes(data))
     }
 
@@ -607,7 +619,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
         if sections.global.size > Self::CLK_CAPS_OFFSET {
             let mut data = [0;4];
             self.read(node, sections.global.offset + Self::CLK_CAPS_OFFSET, &mut data, timeout_ms)
-                .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+                .map_err(|e| section_error(e, GeneralProtocolError::Global))
                 .map(|_| ClockCaps::from(u32::from_be_bytes(data)))
         } else {
             let caps = ClockCaps::new(&[ClockRate::R44100, ClockRate::R48000], &[ClockSource::Internal]);
@@ -621,7 +633,7 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
         if sections.global.size > Self::CLK_NAMES_MAX_SIZE {
             let mut data = vec![0;Self::CLK_NAMES_MAX_SIZE];
             self.read(node, sections.global.offset + Self::CLK_NAMES_OFFSET, &mut data, timeout_ms)
-                .map_err(|e| Error::new(GeneralProtocolError::Global, &e.to_string()))
+                .map_err(|e| section_error(e, GeneralProtocolError::Global))
                 .and_then(|_| {
                     parse_labels(&data[..])
                         .map_err(|e| {
@@ -636,6 +648,20 @@ pub trait GlobalSectionProtocol<T> : GeneralProtocol<T>
             Ok(ClockSourceLabels{entries})
         }
     }
+
+    fn write_clock_source_labels(&self, node: &T, sections: &GeneralSections, labels: &ClockSourceLabels,
+                                 timeout_ms: u32)
+        -> Result<(), Error>
+    {
+        if sections.global.size > Self::CLK_NAMES_MAX_SIZE {
+            let mut data = build_labels(&labels.entries, Self::CLK_NAMES_MAX_SIZE);
+            self.write(node, sections.global.offset + Self::CLK_NAMES_OFFSET, &mut data, timeout_ms)
+                .map_err(|e| section_error(e, GeneralProtocolError::Global))
+        } else {
+            let msg = "Labels for source of sampling clock are not available";
+            Err(Error::new(GeneralProtocolError::Global, &msg))
+        }
+    }
 }
 
 impl<O: AsRef<FwReq>, T: AsRef<FwNode>> GlobalSectionProtocol<T> for O {}
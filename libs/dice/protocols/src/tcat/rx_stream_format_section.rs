@@ -97,7 +97,7 @@ pub trait RxStreamFormatSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;8];
         self.read(node, sections.rx_stream_format.offset, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::RxStreamFormat, &e.to_string()))?;
+            .map_err(|e| section_error(e, GeneralProtocolError::RxStreamFormat))?;
 
         let mut quadlet = [0;4];
         quadlet.copy_from_slice(&data[0..4]);
@@ -110,9 +110,9 @@ pub trait RxStreamFormatSectionProtocol<T> : GeneralProtocol<T>
         let mut data = vec![0;size];
         (0..count).try_for_each(|i| {
             self.read(node, sections.rx_stream_format.offset + 8 + (i * size), &mut data, timeout_ms)
-                .map_err(|e| Error::new(GeneralProtocolError::RxStreamFormat, &e.to_string()))?;
+                .map_err(|e| section_error(e, GeneralProtocolError::RxStreamFormat))?;
             let entry = RxStreamEntry::try_from(&data[..])
-                .map_err(|e| Error::new(GeneralProtocolError::RxStreamFormat, &e.to_string()))?;
+                .map_err(|e| section_error(e, GeneralProtocolError::RxStreamFormat))?;
             entries.push(entry);
             Ok(())
         })?;
@@ -125,7 +125,7 @@ pub trait RxStreamFormatSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;8];
         self.read(node, sections.rx_stream_format.offset, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::RxStreamFormat, &e.to_string()))?;
+            .map_err(|e| section_error(e, GeneralProtocolError::RxStreamFormat))?;
 
         let mut quadlet = [0;4];
         quadlet.copy_from_slice(&data[..4]);
@@ -139,15 +139,15 @@ pub trait RxStreamFormatSectionProtocol<T> : GeneralProtocol<T>
 
             let mut curr = vec![0;size];
             self.read(node, sections.rx_stream_format.offset + 8 + (i * size), &mut curr, timeout_ms)
-                .map_err(|e| Error::new(GeneralProtocolError::RxStreamFormat, &e.to_string()))?;
+                .map_err(|e| section_error(e, GeneralProtocolError::RxStreamFormat))?;
             let curr_fmt = RxStreamEntry::try_from(&curr[..])
-                .map_err(|e| Error::new(GeneralProtocolError::RxStreamFormat, &e.to_string()))?;
+                .map_err(|e| section_error(e, GeneralProtocolError::RxStreamFormat))?;
             expected_fmt.iso_channel = curr_fmt.iso_channel;
 
             if expected_fmt != curr_fmt {
                 let mut raw = Into::<Vec<u8>>::into(&expected_fmt);
                 self.write(node, sections.rx_stream_format.offset + 8 + (i * size), &mut raw, timeout_ms)
-                    .map_err(|e| Error::new(GeneralProtocolError::RxStreamFormat, &e.to_string()))?;
+                    .map_err(|e| section_error(e, GeneralProtocolError::RxStreamFormat))?;
             }
 
             Ok(())
@@ -18,6 +18,31 @@ pub struct Tcd22xxState {
     mixer_blk_pair: (Vec<SrcBlk>, Vec<DstBlk>),
 }
 
+impl Tcd22xxState {
+    /// The mode of rate at which the state is cached.
+    pub fn get_rate_mode(&self) -> RateMode {
+        self.rate_mode
+    }
+
+    /// Whether the source block is available in current rate mode.
+    pub fn is_src_blk_available(&self, src: &SrcBlk) -> bool {
+        self.real_blk_pair.0.iter()
+            .chain(self.stream_blk_pair.0.iter())
+            .chain(self.mixer_blk_pair.0.iter())
+            .find(|s| s.eq(&src))
+            .is_some()
+    }
+
+    /// Whether the destination block is available in current rate mode.
+    pub fn is_dst_blk_available(&self, dst: &DstBlk) -> bool {
+        self.real_blk_pair.1.iter()
+            .chain(self.stream_blk_pair.1.iter())
+            .chain(self.mixer_blk_pair.1.iter())
+            .find(|d| d.eq(&dst))
+            .is_some()
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct Input {
     pub id: SrcBlkId,
@@ -47,6 +72,10 @@ pub trait Tcd22xxSpec {
     // From specification of ADAT/SMUX.
     const ADAT_CHANNELS: [u8;3] = [8, 4, 2];
 
+    // The number of rx streams available at each mode of rate. Some models have rx stream
+    // available at higher mode of rate only.
+    const RX_STREAM_COUNTS: [usize;3] = [2, 2, 2];
+
     fn get_adat_channel_count(rate_mode: RateMode) -> u8 {
         let index = match rate_mode {
             RateMode::Low => 0,
@@ -56,6 +85,15 @@ pub trait Tcd22xxSpec {
         Self::ADAT_CHANNELS[index]
     }
 
+    fn get_rx_stream_count(rate_mode: RateMode) -> usize {
+        let index = match rate_mode {
+            RateMode::Low => 0,
+            RateMode::Middle => 1,
+            RateMode::High => 2,
+        };
+        Self::RX_STREAM_COUNTS[index]
+    }
+
     fn get_mixer_out_port_count(rate_mode: RateMode) -> u8 {
         let index = match rate_mode {
             RateMode::Low => 0,
@@ -69,42 +107,63 @@ pub trait Tcd22xxSpec {
         Self::MIXER_IN_PORTS.iter().fold(0, |accum, (_, count)| accum + count)
     }
 
+    /// Compute the range of channels for each entry of inputs at the mode of rate. The channels of
+    /// ADAT interfaces are packed according to S/MUX.
+    fn compute_input_ranges(rate_mode: RateMode) -> Vec<(&'static Input, u8, u8)> {
+        let mut adat_offset = 0;
+        Self::INPUTS.iter()
+            .map(|entry| {
+                match entry.id {
+                    SrcBlkId::Adat => {
+                        let count = Self::get_adat_channel_count(rate_mode);
+                        let offset = adat_offset;
+                        adat_offset += count;
+                        (entry, offset, count)
+                    }
+                    _ => (entry, entry.offset, entry.count),
+                }
+            })
+            .collect()
+    }
+
+    /// Compute the range of channels for each entry of outputs at the mode of rate. The channels
+    /// of ADAT interfaces are packed according to S/MUX.
+    fn compute_output_ranges(rate_mode: RateMode) -> Vec<(&'static Output, u8, u8)> {
+        let mut adat_offset = 0;
+        Self::OUTPUTS.iter()
+            .map(|entry| {
+                match entry.id {
+                    DstBlkId::Adat => {
+                        let count = Self::get_adat_channel_count(rate_mode);
+                        let offset = adat_offset;
+                        adat_offset += count;
+                        (entry, offset, count)
+                    }
+                    _ => (entry, entry.offset, entry.count),
+                }
+            })
+            .collect()
+    }
+
     fn compute_avail_real_blk_pair(&self, rate_mode: RateMode) -> (Vec<SrcBlk>, Vec<DstBlk>)
     {
-        let mut srcs = Vec::<SrcBlk>::new();
-        Self::INPUTS.iter().for_each(|entry| {
-            let offset = match entry.id {
-                SrcBlkId::Adat => srcs.iter().filter(|&s| s.id.eq(&entry.id)).count() as u8,
-                _ => entry.offset,
-            };
-            let count = match entry.id {
-                SrcBlkId::Adat => Self::get_adat_channel_count(rate_mode),
-                _ => entry.count,
-            };
-            (offset..(offset + count)).for_each(|ch| {
-                srcs.push(SrcBlk{id: entry.id, ch});
-            });
-        });
-
-        let mut dsts = Vec::<DstBlk>::new();
-        Self::OUTPUTS.iter().for_each(|entry| {
-            let offset = match entry.id {
-                DstBlkId::Adat => dsts.iter().filter(|d| d.id.eq(&entry.id)).count() as u8,
-                _ => entry.offset,
-            };
-            let count = match entry.id {
-                DstBlkId::Adat => Self::get_adat_channel_count(rate_mode),
-                _ => entry.count,
-            };
-            (offset..(offset + count)).for_each(|ch| {
-                dsts.push(DstBlk{id: entry.id, ch});
-            });
-        });
+        let srcs = Self::compute_input_ranges(rate_mode).iter()
+            .flat_map(|&(entry, offset, count)| {
+                (offset..(offset + count)).map(move |ch| SrcBlk{id: entry.id, ch})
+            })
+            .collect();
+
+        let dsts = Self::compute_output_ranges(rate_mode).iter()
+            .flat_map(|&(entry, offset, count)| {
+                (offset..(offset + count)).map(move |ch| DstBlk{id: entry.id, ch})
+            })
+            .collect();
 
         (srcs, dsts)
     }
 
-    fn compute_avail_stream_blk_pair(&self, tx_entries: &[FormatEntry], rx_entries: &[FormatEntry])
+    fn compute_avail_stream_blk_pair(&self, tx_entries: &[FormatEntry], rx_entries: &[FormatEntry],
+                                     rate_mode: RateMode)
         -> (Vec<SrcBlk>, Vec<DstBlk>)
     {
         let dst_blk_list = tx_entries.iter()
@@ -116,6 +175,7 @@ pub trait Tcd22xxSpec {
 
         let src_blk_list = rx_entries.iter()
             .zip([SrcBlkId::Avs0, SrcBlkId::Avs1].iter())
+            .take(Self::get_rx_stream_count(rate_mode))
             .map(|(entry, &id)| {
                 (0..entry.pcm_count).map(move |ch| SrcBlk{id, ch})
             }).flatten()
@@ -141,14 +201,16 @@ pub trait Tcd22xxSpec {
         (src_blk_list, dst_blk_list)
     }
 
-    fn get_src_blk_label(&self, src_blk: &SrcBlk) -> String {
-        Self::INPUTS.iter()
-            .find(|entry| {
+    /// Generate label for the source block. The channel of ADAT interface is labelled according to
+    /// the mode of rate, since the channels are packed according to S/MUX.
+    fn get_src_blk_label(&self, src_blk: &SrcBlk, rate_mode: RateMode) -> String {
+        Self::compute_input_ranges(rate_mode).iter()
+            .find(|&&(entry, offset, count)| {
                 entry.id == src_blk.id &&
-                src_blk.ch >= entry.offset && src_blk.ch < entry.offset + entry.count &&
+                src_blk.ch >= offset && src_blk.ch < offset + count &&
                 entry.label.is_some()
             })
-            .map(|entry| format!("{}-{}", entry.label.unwrap(), src_blk.ch - entry.offset))
+            .map(|&(entry, offset, _)| format!("{}-{}", entry.label.unwrap(), src_blk.ch - offset))
             .unwrap_or_else(|| {
                 let name = match src_blk.id {
                     SrcBlkId::Aes => "S/PDIF",
@@ -164,14 +226,16 @@ pub trait Tcd22xxSpec {
             })
     }
 
-    fn get_dst_blk_label(&self, dst_blk: DstBlk) -> String {
-        Self::OUTPUTS.iter()
-            .find(|entry| {
+    /// Generate label for the destination block. The channel of ADAT interface is labelled
+    /// according to the mode of rate, since the channels are packed according to S/MUX.
+    fn get_dst_blk_label(&self, dst_blk: DstBlk, rate_mode: RateMode) -> String {
+        Self::compute_output_ranges(rate_mode).iter()
+            .find(|&&(entry, offset, count)| {
                 entry.id == dst_blk.id &&
-                dst_blk.ch >= entry.offset && dst_blk.ch < entry.offset + entry.count &&
+                dst_blk.ch >= offset && dst_blk.ch < offset + count &&
                 entry.label.is_some()
             })
-            .map(|entry| format!("{}-{}", entry.label.unwrap(), dst_blk.ch - entry.offset))
+            .map(|&(entry, offset, _)| format!("{}-{}", entry.label.unwrap(), dst_blk.ch - offset))
             .unwrap_or_else(|| {
                 let name = match dst_blk.id {
                     DstBlkId::Aes => "S/PDIF",
@@ -254,7 +318,7 @@ pub trait Tcd22xxRouterOperation<T, U> : Tcd22xxSpec + AsRef<Tcd22xxState> + AsM
 
         let (tx_entries, rx_entries) = proto.read_current_stream_format_entries(node, sections, caps,
                                                                                 rate_mode, timeout_ms)?;
-        let stream_blk_pair = self.compute_avail_stream_blk_pair(&tx_entries, &rx_entries);
+        let stream_blk_pair = self.compute_avail_stream_blk_pair(&tx_entries, &rx_entries, rate_mode);
 
         let mixer_blk_pair = self.compute_avail_mixer_blk_pair(caps, rate_mode);
 
@@ -349,3 +413,62 @@ impl<O, T, U> Tcd22xxStateOperation<T, U> for O
           U: CmdSectionProtocol<T> + MixerSectionProtocol<T> + RouterSectionProtocol<T> +
              CurrentConfigSectionProtocol<T>,
 {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Default)]
+    struct TestSpec;
+
+    impl Tcd22xxSpec for TestSpec {
+        const INPUTS: &'static [Input] = &[
+            Input{id: SrcBlkId::Ins0, offset: 0, count: 2, label: None},
+            Input{id: SrcBlkId::Adat, offset: 0, count: 8, label: Some("ADAT-A")},
+            Input{id: SrcBlkId::Adat, offset: 8, count: 8, label: Some("ADAT-B")},
+        ];
+        const OUTPUTS: &'static [Output] = &[
+            Output{id: DstBlkId::Ins0, offset: 0, count: 2, label: None},
+            Output{id: DstBlkId::Adat, offset: 0, count: 8, label: Some("ADAT-A")},
+            Output{id: DstBlkId::Adat, offset: 8, count: 8, label: Some("ADAT-B")},
+        ];
+        const FIXED: &'static [SrcBlk] = &[];
+    }
+
+    #[test]
+    fn avail_real_blk_pair_test() {
+        let spec = TestSpec::default();
+
+        [(RateMode::Low, 16), (RateMode::Middle, 8), (RateMode::High, 4)].iter()
+            .for_each(|&(rate_mode, count)| {
+                let (srcs, dsts) = spec.compute_avail_real_blk_pair(rate_mode);
+                assert_eq!(srcs.iter().filter(|s| s.id == SrcBlkId::Adat).count(), count);
+                assert_eq!(dsts.iter().filter(|d| d.id == DstBlkId::Adat).count(), count);
+                assert_eq!(srcs.iter().filter(|s| s.id == SrcBlkId::Ins0).count(), 2);
+                assert_eq!(dsts.iter().filter(|d| d.id == DstBlkId::Ins0).count(), 2);
+            });
+    }
+
+    #[test]
+    fn blk_label_test() {
+        let spec = TestSpec::default();
+
+        [
+            (RateMode::Low, 7, "ADAT-A-7"),
+            (RateMode::Low, 8, "ADAT-B-0"),
+            (RateMode::Middle, 3, "ADAT-A-3"),
+            (RateMode::Middle, 4, "ADAT-B-0"),
+            (RateMode::High, 1, "ADAT-A-1"),
+            (RateMode::High, 2, "ADAT-B-0"),
+        ].iter()
+            .for_each(|&(rate_mode, ch, label)| {
+                let src = SrcBlk{id: SrcBlkId::Adat, ch};
+                assert_eq!(&spec.get_src_blk_label(&src, rate_mode), label);
+                let dst = DstBlk{id: DstBlkId::Adat, ch};
+                assert_eq!(&spec.get_dst_blk_label(dst, rate_mode), label);
+            });
+
+        let src = SrcBlk{id: SrcBlkId::Ins0, ch: 1};
+        assert_eq!(&spec.get_src_blk_label(&src, RateMode::High), "Analog-A-1");
+    }
+}
@@ -98,7 +98,7 @@ pub trait TxStreamFormatSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;8];
         self.read(node, sections.tx_stream_format.offset, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::TxStreamFormat, &e.to_string()))?;
+            .map_err(|e| section_error(e, GeneralProtocolError::TxStreamFormat))?;
 
         let mut quadlet = [0;4];
         quadlet.copy_from_slice(&data[..4]);
@@ -111,9 +111,9 @@ pub trait TxStreamFormatSectionProtocol<T> : GeneralProtocol<T>
         let mut data = vec![0;size];
         (0..count).try_for_each(|i| {
             self.read(node, sections.tx_stream_format.offset + 8 + (i * size), &mut data, timeout_ms)
-                .map_err(|e| Error::new(GeneralProtocolError::TxStreamFormat, &e.to_string()))?;
+                .map_err(|e| section_error(e, GeneralProtocolError::TxStreamFormat))?;
             let entry = TxStreamFormatEntry::try_from(&data[..])
-                .map_err(|e| Error::new(GeneralProtocolError::TxStreamFormat, &e.to_string()))?;
+                .map_err(|e| section_error(e, GeneralProtocolError::TxStreamFormat))?;
             entries.push(entry);
             Ok(())
         })
@@ -126,7 +126,7 @@ pub trait TxStreamFormatSectionProtocol<T> : GeneralProtocol<T>
     {
         let mut data = [0;8];
         self.read(node, sections.tx_stream_format.offset, &mut data, timeout_ms)
-            .map_err(|e| Error::new(GeneralProtocolError::TxStreamFormat, &e.to_string()))?;
+            .map_err(|e| section_error(e, GeneralProtocolError::TxStreamFormat))?;
 
         let mut quadlet = [0;4];
         quadlet.copy_from_slice(&data[..4]);
@@ -140,15 +140,15 @@ pub trait TxStreamFormatSectionProtocol<T> : GeneralProtocol<T>
 
             let mut curr = vec![0;size];
             self.read(node, sections.tx_stream_format.offset + 8 + (i * size), &mut curr, timeout_ms)
-                .map_err(|e| Error::new(GeneralProtocolError::TxStreamFormat, &e.to_string()))?;
+                .map_err(|e| section_error(e, GeneralProtocolError::TxStreamFormat))?;
             let curr_fmt = TxStreamFormatEntry::try_from(&curr[..])
-                .map_err(|e| Error::new(GeneralProtocolError::TxStreamFormat, &e.to_string()))?;
+                .map_err(|e| section_error(e, GeneralProtocolError::TxStreamFormat))?;
             expected_fmt.iso_channel = curr_fmt.iso_channel;
 
             if expected_fmt != curr_fmt {
                 let mut raw = Into::<Vec<u8>>::into(&expected_fmt);
                 self.write(node, sections.tx_stream_format.offset + 8 + (i * size), &mut raw, timeout_ms)
-                    .map_err(|e| Error::new(GeneralProtocolError::TxStreamFormat, &e.to_string()))?;
+                    .map_err(|e| section_error(e, GeneralProtocolError::TxStreamFormat))?;
             }
 
             Ok(())
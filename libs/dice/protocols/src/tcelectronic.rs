@@ -19,12 +19,15 @@ pub mod midi_send;
 pub mod standalone;
 pub mod prog;
 
-use glib::Error;
+use glib::{Error, FileError};
 
 use hinawa::FwNode;
 
 use super::tcat::*;
 
+/// The offset of address space for segments.
+pub const TC_KONNEKT_SEGMENT_BASE_OFFSET: usize = 0x00a01000;
+
 pub trait TcKonnektSegmentData : Default {
     fn build(&self, raw: &mut [u8]);
     fn parse(&mut self, raw: &[u8]);
@@ -63,7 +66,7 @@ pub trait TcKonnektSegmentProtocol<T, U> : GeneralProtocol<T>
           U: TcKonnektSegmentData,
           TcKonnektSegment<U>: TcKonnektSegmentSpec,
 {
-    const BASE_OFFSET: usize = 0x00a01000;
+    const BASE_OFFSET: usize = TC_KONNEKT_SEGMENT_BASE_OFFSET;
 
     fn read_segment(&self, node: &T, segment: &mut TcKonnektSegment<U>, timeout_ms: u32)
         -> Result<(), Error>
@@ -94,6 +97,59 @@ pub trait TcKonnektSegmentProtocol<T, U> : GeneralProtocol<T>
                 }
             })
     }
+
+    /// Write all quadlets of the segment regardless of the cache, for the operation in which the
+    /// device is expected to receive the write transactions even if the content is not changed.
+    fn write_whole_segment(&self, node: &T, segment: &mut TcKonnektSegment<U>, timeout_ms: u32)
+        -> Result<(), Error>
+    {
+        assert_eq!(segment.raw.len(), TcKonnektSegment::<U>::SIZE, "Programming error...");
+
+        let mut raw = segment.raw.clone();
+        segment.data.build(&mut raw);
+
+        (0..(raw.len() / 4))
+            .map(|i| i * 4)
+            .try_for_each(|pos| {
+                self.write(node, Self::BASE_OFFSET + TcKonnektSegment::<U>::OFFSET + pos,
+                           &mut raw[pos..(pos + 4)], timeout_ms)
+                    .map(|_| segment.raw[pos..(pos + 4)].copy_from_slice(&raw[pos..(pos + 4)]))
+            })
+    }
+
+    /// Write the segment, then read it back to verify that the device accepts the change. It costs
+    /// an additional read transaction, thus should be used just for the change which the device
+    /// possibly rejects silently.
+    fn write_segment_with_verification(&self, node: &T, segment: &mut TcKonnektSegment<U>,
+                                       timeout_ms: u32)
+        -> Result<(), Error>
+    {
+        let mut expected = segment.raw.clone();
+        segment.data.build(&mut expected);
+
+        let changed: Vec<usize> = (0..(expected.len() / 4))
+            .map(|i| i * 4)
+            .filter(|&pos| expected[pos..(pos + 4)] != segment.raw[pos..(pos + 4)])
+            .collect();
+
+        self.write_segment(node, segment, timeout_ms)?;
+
+        let mut raw = vec![0;TcKonnektSegment::<U>::SIZE];
+        self.read(node, Self::BASE_OFFSET + TcKonnektSegment::<U>::OFFSET, &mut raw, timeout_ms)?;
+
+        match changed.iter().find(|&&pos| raw[pos..(pos + 4)] != expected[pos..(pos + 4)]) {
+            Some(&pos) => {
+                // Keep the cache as the same as the actual state of device.
+                segment.raw.copy_from_slice(&raw);
+                segment.data.parse(&segment.raw);
+
+                let msg = format!("The change is not accepted at offset 0x{:04x} of segment",
+                                  TcKonnektSegment::<U>::OFFSET + pos);
+                Err(Error::new(FileError::Io, &msg))
+            }
+            None => Ok(()),
+        }
+    }
 }
 
 impl<O, T, U> TcKonnektSegmentProtocol<T, U> for O
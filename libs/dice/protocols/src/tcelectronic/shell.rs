@@ -15,6 +15,7 @@ use super::*;
 use super::fw_led::*;
 
 use crate::*;
+use crate::tcat::extension::cmd_section::RateMode;
 
 const SHELL_KNOB_NOTIFY_FLAG: u32 = 0x00010000;
 const SHELL_CONFIG_NOTIFY_FLAG: u32 = 0x00020000;
@@ -188,6 +189,19 @@ const SHELL_MIXER_MONITOR_SRC_COUNT: usize = 10;
 
 impl ShellMixerState {
     pub const SIZE: usize = ShellMonitorSrcPair::SIZE * SHELL_MIXER_MONITOR_SRC_COUNT + 36;
+
+    /// The minimum value of output volume.
+    pub const OUTPUT_VOLUME_MIN: i32 = -1000;
+}
+
+/// Get the number of ADAT channels (or pairs) available at the rate mode, against the number at
+/// low rate.
+pub fn get_available_adat_count(count: usize, mode: RateMode) -> usize {
+    match mode {
+        RateMode::Low => count,
+        RateMode::Middle => count / 2,
+        RateMode::High => count / 4,
+    }
 }
 
 /// The type of monitor source.
@@ -228,6 +242,36 @@ pub trait ShellMixerConvert : AsRef<ShellMixerState> + AsMut<ShellMixerState> {
         }
     }
 
+    /// Detect whether each pair of digital inputs is available at the rate mode. The optical
+    /// interface transfers the half of ADAT channels at middle rate and the quarter at high rate
+    /// (S/MUX), while the pair of S/PDIF is always available. The pair shared by ADAT and S/PDIF
+    /// is handled as the pair of ADAT channels.
+    fn get_digital_input_pair_availability(mode: RateMode) -> Vec<bool> {
+        let adat_pair_count = Self::MONITOR_SRC_MAP.iter()
+            .filter(|&&m| {
+                m == Some(ShellMixerMonitorSrcType::Adat) ||
+                m == Some(ShellMixerMonitorSrcType::AdatSpdif)
+            })
+            .count();
+        let avail_adat_pair_count = get_available_adat_count(adat_pair_count, mode);
+
+        let mut adat_pair_idx = 0;
+        Self::MONITOR_SRC_MAP.iter()
+            .filter_map(|&m| {
+                match m {
+                    Some(ShellMixerMonitorSrcType::Spdif) => Some(true),
+                    Some(ShellMixerMonitorSrcType::Adat) |
+                    Some(ShellMixerMonitorSrcType::AdatSpdif) => {
+                        let avail = adat_pair_idx < avail_adat_pair_count;
+                        adat_pair_idx += 1;
+                        Some(avail)
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
     fn build(&self, raw: &mut [u8]) {
         let state = self.as_ref();
 
@@ -364,6 +408,9 @@ pub struct ShellMixerMeter{
 impl ShellMixerMeter {
     pub const SIZE: usize = 0x5c;
 
+    /// The minimum level of meter.
+    pub const LEVEL_MIN: i32 = -1000;
+
     const STREAM_INPUT_COUNT: usize = 2;
     const MAIN_OUTPUT_COUNT: usize = 2;
     const MAX_STREAM_INPUT_COUNT: usize = 8;
@@ -716,3 +763,39 @@ pub trait ShellKnob2TargetSpec {
 }
 
 pub const SHELL_KNOB_SIZE: usize = 36;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::{klive::*, itwin::*};
+    use crate::tcelectronic::reverb::ReverbMeter;
+
+    // The fake memory space of which each quadlet has its own address is parsed, so that the
+    // offset of segment is checked.
+    fn check_reverb_meter_segment_range<U>(offset: usize)
+        where U: TcKonnektSegmentData + AsRef<ReverbMeter>,
+              TcKonnektSegment<U>: TcKonnektSegmentSpec,
+    {
+        assert_eq!(TcKonnektSegment::<U>::OFFSET, offset);
+        assert_eq!(TcKonnektSegment::<U>::SIZE, ReverbMeter::SIZE);
+
+        let mem: Vec<u8> = (0..(0x1100 / 4))
+            .flat_map(|i| ((i * 4) as u32).to_be_bytes().to_vec())
+            .collect();
+
+        let size = TcKonnektSegment::<U>::SIZE;
+        let mut meter = U::default();
+        TcKonnektSegmentData::parse(&mut meter, &mem[offset..(offset + size)]);
+
+        let state = meter.as_ref();
+        let offset = offset as i32;
+        assert_eq!(state.outputs, [offset, offset + 4]);
+        assert_eq!(state.inputs, [offset + 8, offset + 12]);
+    }
+
+    #[test]
+    fn reverb_meter_segment_range() {
+        check_reverb_meter_segment_range::<KliveReverbMeter>(0x10c4);
+        check_reverb_meter_segment_range::<ItwinReverbMeter>(0x10c8);
+    }
+}
@@ -8,6 +8,7 @@
 
 use super::*;
 use crate::tcelectronic::{*, ch_strip::*, reverb::*, standalone::*, midi_send::*, prog::*};
+use crate::tcat::extension::cmd_section::RateMode;
 
 /// The structure to represent segments in memory space of Konnekt Live.
 #[derive(Default, Debug)]
@@ -278,6 +279,8 @@ impl From<u32> for ChStripSrc {
     }
 }
 
+// NOTE: the device uses 11 for no source. The value is not symmetric since any unknown value is
+// parsed as no source.
 impl From<ChStripSrc> for u32 {
     fn from(src: ChStripSrc) -> Self {
         match src {
@@ -577,6 +580,19 @@ impl ShellMixerMeterConvert for KliveMixerMeter {
     const DIGITAL_INPUT_COUNT: usize = KLIVE_METER_DIGITAL_INPUT_COUNT;
 }
 
+impl KliveMixerMeter {
+    /// Mark the meters for ADAT channels unavailable at the rate mode as inactive, by the minimum
+    /// level. The meters for digital inputs are assumed to be in the same order as the digital
+    /// monitor sources of mixer, thus the same availability is applied.
+    pub fn mask_unavailable_digital_inputs(&mut self, mode: RateMode) {
+        let avail_list = KliveMixerState::get_digital_input_pair_availability(mode);
+        self.0.digital_inputs.iter_mut()
+            .enumerate()
+            .filter(|(i, _)| avail_list.get(i / 2) == Some(&false))
+            .for_each(|(_, m)| *m = ShellMixerMeter::LEVEL_MIN);
+    }
+}
+
 impl TcKonnektSegmentData for KliveMixerMeter {
     fn build(&self, raw: &mut [u8]) {
         ShellMixerMeterConvert::build(self, raw)
@@ -682,3 +698,117 @@ impl From<OutputImpedance> for u32 {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ch_strip_src_from() {
+        [
+            ChStripSrc::Stream01,
+            ChStripSrc::Analog01,
+            ChStripSrc::Analog23,
+            ChStripSrc::Digital01,
+            ChStripSrc::Digital23,
+            ChStripSrc::Digital45,
+            ChStripSrc::Digital67,
+            ChStripSrc::MixerOutput,
+            ChStripSrc::None,
+        ].iter()
+            .for_each(|&src| {
+                let val = u32::from(src);
+                assert_eq!(src, ChStripSrc::from(val));
+            });
+
+        assert_eq!(11, u32::from(ChStripSrc::None));
+        assert_eq!(ChStripSrc::None, ChStripSrc::from(11));
+    }
+
+    #[test]
+    fn mixer_meter_parse() {
+        let raw: Vec<u8> = (0..ShellMixerMeter::SIZE)
+            .map(|i| i as u8)
+            .collect();
+
+        let mut meter = KliveMixerMeter::default();
+        TcKonnektSegmentData::parse(&mut meter, &raw);
+
+        let state = meter.as_ref();
+        assert_eq!(state.analog_inputs.len(), KLIVE_METER_ANALOG_INPUT_COUNT);
+        assert_eq!(state.digital_inputs.len(), KLIVE_METER_DIGITAL_INPUT_COUNT);
+
+        assert_eq!(state.stream_inputs[0], i32::from_be_bytes([0x00, 0x01, 0x02, 0x03]));
+        assert_eq!(state.analog_inputs[0], i32::from_be_bytes([0x20, 0x21, 0x22, 0x23]));
+        assert_eq!(state.digital_inputs[7], i32::from_be_bytes([0x4c, 0x4d, 0x4e, 0x4f]));
+        assert_eq!(state.main_outputs[1], i32::from_be_bytes([0x54, 0x55, 0x56, 0x57]));
+
+        let mut target = vec![0;ShellMixerMeter::SIZE];
+        TcKonnektSegmentData::build(&meter, &mut target);
+        assert_eq!(&target[..0x08], &raw[..0x08]);
+        assert_eq!(&target[0x20..0x58], &raw[0x20..0x58]);
+    }
+
+    #[test]
+    fn mixer_meter_mask() {
+        [
+            (RateMode::Low, 8),
+            (RateMode::Middle, 6),
+            (RateMode::High, 4),
+        ].iter()
+            .for_each(|&(mode, count)| {
+                let mut meter = KliveMixerMeter::default();
+                meter.0.digital_inputs.iter_mut()
+                    .for_each(|m| *m = 0);
+                meter.mask_unavailable_digital_inputs(mode);
+                meter.0.digital_inputs.iter()
+                    .enumerate()
+                    .for_each(|(i, &m)| {
+                        if i < count {
+                            assert_eq!(m, 0);
+                        } else {
+                            assert_eq!(m, ShellMixerMeter::LEVEL_MIN);
+                        }
+                    });
+            });
+    }
+
+    #[test]
+    fn config_segment_range() {
+        let size = TcKonnektSegment::<KliveConfig>::SIZE;
+        let raw: Vec<u8> = (0..size)
+            .map(|i| if i % 4 == 3 { 1 } else { 0 })
+            .collect();
+
+        let mut config = KliveConfig::default();
+        TcKonnektSegmentData::parse(&mut config, &raw);
+
+        let mut target = vec![0;size];
+        TcKonnektSegmentData::build(&config, &mut target);
+
+        let mut cloned = KliveConfig::default();
+        TcKonnektSegmentData::parse(&mut cloned, &target);
+        assert_eq!(config.out_01_src, cloned.out_01_src);
+        assert_eq!(config.standalone_src, cloned.standalone_src);
+        assert_eq!(config.midi_sender, cloned.midi_sender);
+    }
+
+    #[test]
+    fn mixer_state_segment_range() {
+        let size = TcKonnektSegment::<KliveMixerState>::SIZE;
+
+        let mut state = KliveMixerState::default();
+        state.use_ch_strip_as_plugin = true;
+        state.ch_strip_src = ChStripSrc::Digital45;
+        state.enabled = true;
+
+        let mut raw = vec![0;size];
+        TcKonnektSegmentData::build(&state, &mut raw);
+
+        let mut cloned = KliveMixerState::default();
+        TcKonnektSegmentData::parse(&mut cloned, &raw);
+        assert_eq!(state.use_ch_strip_as_plugin, cloned.use_ch_strip_as_plugin);
+        assert_eq!(state.ch_strip_src, cloned.ch_strip_src);
+        assert_eq!(state.enabled, cloned.enabled);
+    }
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2020 Takashi Sakamoto
+use std::{env, fs, path::Path};
+
+const OFFSETS_ENV_NAME: &str = "TC_SEGMENT_DEBUG_OFFSETS";
+const OFFSETS_FILE_NAME: &str = "segment_debug_offsets.rs";
+
+// The offsets of quadlet are checked at build time, so that invalid offsets never fail loading
+// of model at runtime.
+fn parse_offsets(literal: &str) -> Result<Vec<usize>, String> {
+    literal.split(',')
+        .map(|entry| entry.trim())
+        .filter(|entry| entry.len() > 0)
+        .map(|entry| {
+            let digits = entry.trim_start_matches("0x");
+            usize::from_str_radix(digits, 16)
+                .ok()
+                .filter(|offset| offset % 4 == 0)
+                .ok_or_else(|| format!("Invalid offset of quadlet in segment: {}", entry))
+        })
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={}", OFFSETS_ENV_NAME);
+
+    if env::var_os("CARGO_FEATURE_TC_SEGMENT_DEBUG").is_none() {
+        return;
+    }
+
+    let offsets = match env::var(OFFSETS_ENV_NAME) {
+        Ok(literal) => {
+            parse_offsets(&literal)
+                .unwrap_or_else(|msg| panic!("{}: {}", OFFSETS_ENV_NAME, msg))
+        }
+        Err(_) => Vec::new(),
+    };
+
+    let entries = offsets.iter()
+        .map(|offset| format!("0x{:04x}", offset))
+        .collect::<Vec<_>>();
+    let code = format!("const OFFSETS: &[usize] = &[{}];\n", entries.join(", "));
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join(OFFSETS_FILE_NAME);
+    fs::write(&path, code).unwrap();
+}
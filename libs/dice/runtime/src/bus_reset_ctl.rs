@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2020 Takashi Sakamoto
+use glib::Error;
+
+use alsactl::{ElemId, ElemIfaceType, ElemValue};
+
+use hinawa::SndDice;
+
+use core::card_cntr::*;
+use core::elem_value_accessor::ElemValueAccessor;
+
+/// The structure to represent the count of bus reset observed by the runtime, so that clients can
+/// detect the reset to read state again. The element is not unlocked, thus clients can not write
+/// to it.
+#[derive(Default)]
+pub struct BusResetCtl {
+    count: i32,
+    pub notified_elem_list: Vec<ElemId>,
+}
+
+impl BusResetCtl {
+    const BUS_RESET_COUNT_NAME: &'static str = "bus-reset-count";
+}
+
+impl CtlModel<SndDice> for BusResetCtl {
+    fn load(&mut self, _: &mut SndDice, card_cntr: &mut CardCntr) -> Result<(), Error> {
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::BUS_RESET_COUNT_NAME, 0);
+        card_cntr.add_int_elems(&elem_id, 1, 0, i32::MAX, 1, 1, None, false)
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))
+    }
+
+    fn read(&mut self, _: &mut SndDice, elem_id: &ElemId, elem_value: &mut ElemValue)
+        -> Result<bool, Error>
+    {
+        match elem_id.get_name().as_str() {
+            Self::BUS_RESET_COUNT_NAME => {
+                ElemValueAccessor::<i32>::set_val(elem_value, || Ok(self.count))?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn write(&mut self, _: &mut SndDice, _: &ElemId, _: &ElemValue, _: &ElemValue)
+        -> Result<bool, Error>
+    {
+        Ok(false)
+    }
+}
+
+impl NotifyModel<SndDice, u32> for BusResetCtl {
+    fn get_notified_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        elem_id_list.extend_from_slice(&self.notified_elem_list);
+    }
+
+    fn parse_notification(&mut self, _: &mut SndDice, _: &u32) -> Result<(), Error> {
+        // The value wraps to zero after the maximum.
+        self.count = if self.count < i32::MAX {
+            self.count + 1
+        } else {
+            0
+        };
+        Ok(())
+    }
+
+    fn read_notified_elem(&mut self, _: &SndDice, elem_id: &ElemId, elem_value: &mut ElemValue)
+        -> Result<bool, Error>
+    {
+        match elem_id.get_name().as_str() {
+            Self::BUS_RESET_COUNT_NAME => {
+                ElemValueAccessor::<i32>::set_val(elem_value, || Ok(self.count))?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
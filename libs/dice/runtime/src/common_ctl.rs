@@ -12,15 +12,21 @@ use core::card_cntr::*;
 use core::elem_value_accessor::ElemValueAccessor;
 
 use dice_protocols::tcat::{*, global_section::*};
+use dice_protocols::tcat::extension::cmd_section::RateMode;
 
 #[derive(Default)]
 pub struct CommonCtl {
     rates: Vec<ClockRate>,
     srcs: Vec<ClockSource>,
+    src_labels: ClockSourceLabels,
     curr_rate_idx: u32,
     curr_src_idx: u32,
+    internal_rate_idx: u32,
     ext_srcs: Vec<ClockSource>,
     ext_src_states: ExtSourceStates,
+    lock_loss_count: u32,
+    ext_src_is_slipped: bool,
+    slip_count: u32,
     pub notified_elem_list: Vec<ElemId>,
     pub measured_elem_list: Vec<ElemId>,
 }
@@ -30,6 +36,18 @@ const CLK_SRC_NAME: &str = "clock-source";
 const NICKNAME: &str = "nickname";
 const LOCKED_CLK_SRC_NAME: &str = "locked-clock-source";
 const SLIPPED_CLK_SRC_NAME: &str = "slipped-clock-source";
+const PROTOCOL_VERSION_NAME: &str = "tcat-protocol-version";
+const CLK_SRC_NICKNAME_NAME: &str = "clock-source-nickname";
+const CLK_RATE_MODE_NAME: &str = "clock-rate-mode";
+const EXT_CLK_LOCKED_NAME: &str = "external-clock-locked";
+const LOCK_LOSS_COUNT_NAME: &str = "clock-lock-loss-count";
+const SLIP_COUNT_NAME: &str = "clock-slip-count";
+const INTERNAL_CLK_RATE_NAME: &str = "internal-clock-rate";
+
+const RATE_MODES: [RateMode;3] = [RateMode::Low, RateMode::Middle, RateMode::High];
+
+const PROTOCOL_VERSION_LABEL_SIZE: usize = 16;
+const CLK_SRC_NICKNAME_SIZE: usize = 32;
 
 impl CommonCtl {
     pub fn load(&mut self, card_cntr: &mut CardCntr, caps: &ClockCaps, src_labels: &ClockSourceLabels)
@@ -54,9 +72,36 @@ impl CommonCtl {
         let mut elem_id_list = card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)?;
         self.notified_elem_list.append(&mut elem_id_list);
 
+        // The source and rate are in the same register, thus they are changed by one transaction.
+        if self.srcs.iter().any(|&s| s == ClockSource::Internal) {
+            let labels = self.rates.iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>();
+
+            let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, INTERNAL_CLK_RATE_NAME, 0);
+            let mut elem_id_list = card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)?;
+            self.notified_elem_list.append(&mut elem_id_list);
+        }
+
+        let labels = RATE_MODES.iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>();
+
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, CLK_RATE_MODE_NAME, 0);
+        let mut elem_id_list = card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, false)?;
+        self.notified_elem_list.append(&mut elem_id_list);
+
         let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, NICKNAME, 0);
         let _ = card_cntr.add_bytes_elems(&elem_id, 1, NICKNAME_MAX_SIZE, None, true)?;
 
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, PROTOCOL_VERSION_NAME, 0);
+        let _ = card_cntr.add_bytes_elems(&elem_id, 1, PROTOCOL_VERSION_LABEL_SIZE, None, false)?;
+
+        self.src_labels = src_labels.clone();
+
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, CLK_SRC_NICKNAME_NAME, 0);
+        let _ = card_cntr.add_bytes_elems(&elem_id, self.srcs.len(), CLK_SRC_NICKNAME_SIZE, None, true)?;
+
         self.ext_srcs = ExtSourceStates::get_entries(caps, src_labels);
         let labels = self.ext_srcs.iter()
             .map(|s| s.get_label(src_labels, true).unwrap())
@@ -66,10 +111,22 @@ impl CommonCtl {
         let mut elem_id_list = card_cntr.add_bool_elems(&elem_id, 1, labels.len(), false)?;
         self.notified_elem_list.append(&mut elem_id_list);
 
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, EXT_CLK_LOCKED_NAME, 0);
+        let mut elem_id_list = card_cntr.add_bool_elems(&elem_id, 1, 1, false)?;
+        self.notified_elem_list.append(&mut elem_id_list);
+
         let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, SLIPPED_CLK_SRC_NAME, 0);
         let mut elem_id_list = card_cntr.add_bool_elems(&elem_id, 1, labels.len(), false)?;
         self.measured_elem_list.append(&mut elem_id_list);
 
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, LOCK_LOSS_COUNT_NAME, 0);
+        let mut elem_id_list = card_cntr.add_int_elems(&elem_id, 1, 0, i32::MAX, 1, 1, None, false)?;
+        self.notified_elem_list.append(&mut elem_id_list);
+
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, SLIP_COUNT_NAME, 0);
+        let mut elem_id_list = card_cntr.add_int_elems(&elem_id, 1, 0, i32::MAX, 1, 1, None, false)?;
+        self.measured_elem_list.append(&mut elem_id_list);
+
         Ok(())
     }
 
@@ -87,7 +144,62 @@ impl CommonCtl {
                 let msg = format!("Unexpected value read for clock source: {}", config.src);
                 Error::new(FileError::Io, &msg)
             })
-            .map(|pos| self.curr_src_idx = pos as u32)
+            .map(|pos| self.curr_src_idx = pos as u32)?;
+
+        // The rate for internal source is kept while external source is selected.
+        if config.src == ClockSource::Internal {
+            self.internal_rate_idx = self.curr_rate_idx;
+        }
+
+        Ok(())
+    }
+
+    /// Read current clock configuration from the device and cache it.
+    pub fn cache_clock_state<T: AsRef<FwReq>>(&mut self, unit: &SndDice, proto: &T,
+                                              sections: &GeneralSections, timeout_ms: u32)
+        -> Result<(), Error>
+    {
+        let config = proto.read_clock_config(&unit.get_node(), sections, timeout_ms)?;
+        self.cache_clock_config(&config)
+    }
+
+    // The slip is counted at the transition of the slipped bit from unset to set.
+    fn cache_ext_src_states(&mut self, states: ExtSourceStates) {
+        self.ext_src_states = states;
+        let slipped = self.is_curr_ext_src_slipped();
+        if slipped && !self.ext_src_is_slipped {
+            self.slip_count = self.slip_count.saturating_add(1);
+        }
+        self.ext_src_is_slipped = slipped;
+    }
+
+    /// Retrieve the mode of sampling rate in the cache.
+    pub fn get_curr_rate_mode(&self) -> RateMode {
+        self.rates.get(self.curr_rate_idx as usize)
+            .map(|&r| RateMode::from(r))
+            .unwrap_or_default()
+    }
+
+    fn get_curr_rate_mode_idx(&self) -> u32 {
+        let mode = self.get_curr_rate_mode();
+        RATE_MODES.iter()
+            .position(|&m| m == mode)
+            .unwrap() as u32
+    }
+
+    // The internal source is not external, thus never locked.
+    fn is_curr_ext_src_locked(&self) -> bool {
+        self.srcs.get(self.curr_src_idx as usize)
+            .filter(|src| self.ext_srcs.iter().any(|s| s == *src))
+            .map(|src| src.is_locked(&self.ext_src_states))
+            .unwrap_or(false)
+    }
+
+    fn is_curr_ext_src_slipped(&self) -> bool {
+        self.srcs.get(self.curr_src_idx as usize)
+            .filter(|src| self.ext_srcs.iter().any(|s| s == *src))
+            .map(|src| src.is_slipped(&self.ext_src_states))
+            .unwrap_or(false)
     }
 
     pub fn read<T: AsRef<FwReq>>(&mut self, unit: &SndDice, proto: &T, sections: &GeneralSections,
@@ -101,12 +213,24 @@ impl CommonCtl {
                 ElemValueAccessor::<u32>::set_val(elem_value, || Ok(self.curr_rate_idx))
                 .map(|_| true)
             }
+            INTERNAL_CLK_RATE_NAME => {
+                let config = proto.read_clock_config(&unit.get_node(), sections, timeout_ms)?;
+                self.cache_clock_config(&config)?;
+                ElemValueAccessor::<u32>::set_val(elem_value, || Ok(self.internal_rate_idx))
+                .map(|_| true)
+            }
             CLK_SRC_NAME => {
                 let config = proto.read_clock_config(&unit.get_node(), sections, timeout_ms)?;
                 self.cache_clock_config(&config)?;
                 ElemValueAccessor::<u32>::set_val(elem_value, || Ok(self.curr_src_idx))
                 .map(|_| true)
             }
+            CLK_RATE_MODE_NAME => {
+                let config = proto.read_clock_config(&unit.get_node(), sections, timeout_ms)?;
+                self.cache_clock_config(&config)?;
+                ElemValueAccessor::<u32>::set_val(elem_value, || Ok(self.get_curr_rate_mode_idx()))
+                .map(|_| true)
+            }
             NICKNAME => {
                 proto.read_nickname(&unit.get_node(), sections, timeout_ms)
                     .map(|name| {
@@ -117,6 +241,28 @@ impl CommonCtl {
                         true
                     })
             }
+            PROTOCOL_VERSION_NAME => {
+                proto.read_version(&unit.get_node(), sections, timeout_ms)
+                    .map(|version| {
+                        let mut vals = vec![0;PROTOCOL_VERSION_LABEL_SIZE];
+                        let label = version_to_string(version);
+                        let raw = label.as_bytes();
+                        vals[..raw.len()].copy_from_slice(&raw);
+                        elem_value.set_bytes(&vals);
+                        true
+                    })
+            }
+            CLK_SRC_NICKNAME_NAME => {
+                let src = &self.srcs[elem_id.get_index() as usize];
+                let mut vals = vec![0;CLK_SRC_NICKNAME_SIZE];
+                if let Some(label) = src.get_label(&self.src_labels, false) {
+                    let raw = label.as_bytes();
+                    let len = std::cmp::min(raw.len(), CLK_SRC_NICKNAME_SIZE - 1);
+                    vals[..len].copy_from_slice(&raw[..len]);
+                }
+                elem_value.set_bytes(&vals);
+                Ok(true)
+            }
             _ => Ok(false),
         }
     }
@@ -147,6 +293,22 @@ impl CommonCtl {
         Ok(())
     }
 
+    // Both of rate and source are changed in the configuration, or none of them at error.
+    fn update_clock_config_to_internal(&mut self, config: &mut ClockConfig, rate: u32)
+        -> Result<u32, Error>
+    {
+        let src = self.srcs.iter()
+            .position(|&s| s == ClockSource::Internal)
+            .ok_or_else(|| {
+                let msg = "Internal source is not available";
+                Error::new(FileError::Nxio, &msg)
+            })? as u32;
+        let mut cfg = *config;
+        self.update_clock_config(&mut cfg, Some(rate), Some(src))?;
+        *config = cfg;
+        Ok(src)
+    }
+
     pub fn write<T: AsRef<FwReq>>(&mut self, unit: &SndDice, proto: &T, sections: &GeneralSections,
                                   elem_id: &ElemId, _: &ElemValue, new: &ElemValue, timeout_ms: u32)
         -> Result<bool, Error>
@@ -160,8 +322,7 @@ impl CommonCtl {
                             self.update_clock_config(&mut config, Some(val as u32), None)?;
                             proto.write_clock_config(&unit.get_node(), sections, config,
                                                      timeout_ms)?;
-                            self.curr_rate_idx = val;
-                            Ok(())
+                            self.cache_clock_config(&config)
                         });
                     let _ = unit.unlock();
                     res
@@ -176,7 +337,24 @@ impl CommonCtl {
                             self.update_clock_config(&mut config, None, Some(val as u32))?;
                             proto.write_clock_config(&unit.get_node(), sections, config,
                                                      timeout_ms)?;
-                            self.curr_src_idx = val;
+                            self.cache_clock_config(&config)
+                        });
+                    let _ = unit.unlock();
+                    res
+                })
+                .map(|_| true)
+            }
+            INTERNAL_CLK_RATE_NAME => {
+                ElemValueAccessor::<u32>::get_val(new, |val| {
+                    unit.lock()?;
+                    let res = proto.read_clock_config(&unit.get_node(), sections, timeout_ms)
+                        .and_then(|mut config| {
+                            let src_idx = self.update_clock_config_to_internal(&mut config, val)?;
+                            proto.write_clock_config(&unit.get_node(), sections, config,
+                                                     timeout_ms)?;
+                            self.curr_rate_idx = val;
+                            self.curr_src_idx = src_idx;
+                            self.internal_rate_idx = val;
                             Ok(())
                         });
                     let _ = unit.unlock();
@@ -187,20 +365,34 @@ impl CommonCtl {
             NICKNAME => {
                 let mut vals = vec![0;NICKNAME_MAX_SIZE];
                 new.get_bytes(&mut vals);
-                std::str::from_utf8(&vals)
-                    .map_err(|e| {
-                        let msg = format!("Invalid bytes for string: {}", e);
-                        Error::new(FileError::Inval, &msg)
-                    })
-                    .and_then(|text| {
-                        text.find('\0')
-                            .ok_or(Error::new(FileError::Inval, "Unterminated string found"))
-                            .and_then(|pos| {
-                                proto.write_nickname(&unit.get_node(), sections, &text[..pos], timeout_ms)
-                            })
-                    })
+                parse_bytes_as_string(&vals)
+                    .and_then(|text| proto.write_nickname(&unit.get_node(), sections, text, timeout_ms))
                     .map(|_| true)
             }
+            CLK_SRC_NICKNAME_NAME => {
+                let mut vals = vec![0;CLK_SRC_NICKNAME_SIZE];
+                new.get_bytes(&mut vals);
+                let text = parse_bytes_as_string(&vals)?;
+
+                let src = &self.srcs[elem_id.get_index() as usize];
+                let idx = u8::from(*src) as usize;
+                let mut labels = self.src_labels.clone();
+                if labels.entries.len() <= idx {
+                    labels.entries.resize(idx + 1, String::new());
+                }
+                labels.entries[idx] = text.to_string();
+
+                if !labels.is_storable() {
+                    let msg = format!("Invalid label for clock source: {}", text);
+                    return Err(Error::new(FileError::Inval, &msg));
+                }
+
+                proto.write_clock_source_labels(&unit.get_node(), sections, &labels, timeout_ms)
+                    .map(|_| {
+                        self.src_labels = labels;
+                        true
+                    })
+            }
             _ => Ok(false),
         }
     }
@@ -215,7 +407,17 @@ impl CommonCtl {
         }
 
         if msg.has_ext_status_changed() {
-            self.ext_src_states = proto.read_clock_source_states(&unit.get_node(), sections, timeout_ms)?;
+            let states = proto.read_clock_source_states(&unit.get_node(), sections, timeout_ms)?;
+            self.cache_ext_src_states(states);
+        }
+
+        // The notification is sent at the change of lock status, thus the status read as unlocked
+        // means the loss of lock.
+        if msg.has_lock_changed() {
+            let status = proto.read_clock_status(&unit.get_node(), sections, timeout_ms)?;
+            if !status.src_is_locked {
+                self.lock_loss_count = self.lock_loss_count.saturating_add(1);
+            }
         }
 
         Ok(())
@@ -229,16 +431,34 @@ impl CommonCtl {
                 ElemValueAccessor::<u32>::set_val(elem_value, || Ok(self.curr_rate_idx))
                 .map(|_| true)
             }
+            INTERNAL_CLK_RATE_NAME => {
+                ElemValueAccessor::<u32>::set_val(elem_value, || Ok(self.internal_rate_idx))
+                .map(|_| true)
+            }
             CLK_SRC_NAME => {
                 ElemValueAccessor::<u32>::set_val(elem_value, || Ok(self.curr_src_idx))
                 .map(|_| true)
             }
+            CLK_RATE_MODE_NAME => {
+                ElemValueAccessor::<u32>::set_val(elem_value, || Ok(self.get_curr_rate_mode_idx()))
+                .map(|_| true)
+            }
             LOCKED_CLK_SRC_NAME => {
                 ElemValueAccessor::<bool>::set_vals(elem_value, self.ext_srcs.len(), |idx| {
                     Ok(self.ext_srcs[idx].is_locked(&self.ext_src_states))
                 })
                 .map(|_| true)
             }
+            EXT_CLK_LOCKED_NAME => {
+                ElemValueAccessor::<bool>::set_val(elem_value, || Ok(self.is_curr_ext_src_locked()))
+                .map(|_| true)
+            }
+            LOCK_LOSS_COUNT_NAME => {
+                ElemValueAccessor::<i32>::set_val(elem_value, || {
+                    Ok(std::cmp::min(self.lock_loss_count, i32::MAX as u32) as i32)
+                })
+                .map(|_| true)
+            }
             _ => Ok(false),
         }
     }
@@ -248,7 +468,7 @@ impl CommonCtl {
         -> Result<(), Error>
     {
         proto.read_clock_source_states(&unit.get_node(), sections, timeout_ms)
-            .map(|states| self.ext_src_states = states)
+            .map(|states| self.cache_ext_src_states(states))
     }
 
     pub fn measure_elem(&mut self, elem_id: &ElemId, elem_value: &ElemValue) -> Result<bool, Error> {
@@ -259,7 +479,94 @@ impl CommonCtl {
                 })
                 .map(|_| true)
             }
+            SLIP_COUNT_NAME => {
+                ElemValueAccessor::<i32>::set_val(elem_value, || {
+                    Ok(std::cmp::min(self.slip_count, i32::MAX as u32) as i32)
+                })
+                .map(|_| true)
+            }
             _ => Ok(false),
         }
     }
 }
+
+fn parse_bytes_as_string(vals: &[u8]) -> Result<&str, Error> {
+    std::str::from_utf8(vals)
+        .map_err(|e| {
+            let msg = format!("Invalid bytes for string: {}", e);
+            Error::new(FileError::Inval, &msg)
+        })
+        .and_then(|text| {
+            text.find('\0')
+                .ok_or(Error::new(FileError::Inval, "Unterminated string found"))
+                .map(|pos| &text[..pos])
+        })
+}
+
+// The value consists of major, minor, sub-minor, and build in each byte from MSB.
+fn version_to_string(version: u32) -> String {
+    format!("{}.{}.{}.{}",
+            (version >> 24) & 0xff, (version >> 16) & 0xff, (version >> 8) & 0xff, version & 0xff)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_update_clock_config_to_internal() {
+        let mut ctl = CommonCtl::default();
+        ctl.rates = vec![ClockRate::R44100, ClockRate::R48000];
+        ctl.srcs = vec![ClockSource::Aes1, ClockSource::Internal];
+
+        let mut config = ClockConfig{rate: ClockRate::R44100, src: ClockSource::Aes1};
+        assert_eq!(ctl.update_clock_config_to_internal(&mut config, 1).unwrap(), 1);
+        assert_eq!(config, ClockConfig{rate: ClockRate::R48000, src: ClockSource::Internal});
+
+        let mut config = ClockConfig{rate: ClockRate::R44100, src: ClockSource::Aes1};
+        let err = ctl.update_clock_config_to_internal(&mut config, 2).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert_eq!(config, ClockConfig{rate: ClockRate::R44100, src: ClockSource::Aes1});
+
+        ctl.srcs = vec![ClockSource::Aes1];
+        let err = ctl.update_clock_config_to_internal(&mut config, 1).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Nxio));
+        assert_eq!(config, ClockConfig{rate: ClockRate::R44100, src: ClockSource::Aes1});
+    }
+
+    #[test]
+    fn test_cache_clock_config_internal_rate() {
+        let mut ctl = CommonCtl::default();
+        ctl.rates = vec![ClockRate::R44100, ClockRate::R48000];
+        ctl.srcs = vec![ClockSource::Aes1, ClockSource::Internal];
+
+        let config = ClockConfig{rate: ClockRate::R44100, src: ClockSource::Internal};
+        ctl.cache_clock_config(&config).unwrap();
+        assert_eq!(ctl.internal_rate_idx, 0);
+
+        let config = ClockConfig{rate: ClockRate::R48000, src: ClockSource::Aes1};
+        ctl.cache_clock_config(&config).unwrap();
+        assert_eq!(ctl.curr_rate_idx, 1);
+        assert_eq!(ctl.internal_rate_idx, 0);
+    }
+
+    #[test]
+    fn test_refresh_of_tc_konnekt_segments() {
+        use crate::tcelectronic::TcKonnektSegments;
+        use dice_protocols::tcelectronic::{shell::klive::KliveSegments, desktop::DesktopSegments};
+
+        // No transaction is expected, thus the unit is not opened.
+        let unit = SndDice::new();
+        let proto = FwReq::new();
+        let sections = GeneralSections::default();
+
+        let mut ctl = CommonCtl::default();
+        let msg = KliveSegments::default().get_notified_flags();
+        ctl.parse_notification(&unit, &proto, &sections, msg, 0).unwrap();
+        assert_eq!(ctl.lock_loss_count, 0);
+
+        let msg = DesktopSegments::default().get_notified_flags();
+        ctl.parse_notification(&unit, &proto, &sections, msg, 0).unwrap();
+        assert_eq!(ctl.lock_loss_count, 0);
+    }
+}
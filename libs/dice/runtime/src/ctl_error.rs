@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2020 Takashi Sakamoto
+use glib::{Error, FileError, error::ErrorDomain, Quark};
+
+use hinawa::{FwNodeError, SndUnitError};
+
+use dice_protocols::tcat::GeneralProtocolError;
+
+/// The enumeration to represent the cause of failure in control operation.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CtlError {
+    /// The value is not supported by the device or the model.
+    UnsupportedValue,
+    /// The device is used or locked by the other process.
+    DeviceBusy,
+    /// The device gives no response in time.
+    Timeout,
+    /// The device is not available anymore.
+    Disconnected,
+    /// The other failure in transaction to the device.
+    Io,
+    /// The code out of the enumeration, retrieved from the error in the domain.
+    Invalid(i32),
+}
+
+impl std::fmt::Display for CtlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let msg = match self {
+            CtlError::UnsupportedValue => "unsupported-value",
+            CtlError::DeviceBusy => "device-busy",
+            CtlError::Timeout => "timeout",
+            CtlError::Disconnected => "disconnected",
+            CtlError::Io => "io",
+            CtlError::Invalid(_) => "invalid",
+        };
+
+        write!(f, "CtlError::{}", msg)
+    }
+}
+
+impl ErrorDomain for CtlError {
+    fn domain() -> Quark {
+        Quark::from_string("dice-ctl-error-quark")
+    }
+
+    fn code(self) -> i32 {
+        match self {
+            CtlError::UnsupportedValue => 0,
+            CtlError::DeviceBusy => 1,
+            CtlError::Timeout => 2,
+            CtlError::Disconnected => 3,
+            CtlError::Io => 4,
+            CtlError::Invalid(v) => v,
+        }
+    }
+
+    fn from(code: i32) -> Option<Self> {
+        let enumeration = match code {
+            0 => CtlError::UnsupportedValue,
+            1 => CtlError::DeviceBusy,
+            2 => CtlError::Timeout,
+            3 => CtlError::Disconnected,
+            4 => CtlError::Io,
+            _ => CtlError::Invalid(code),
+        };
+        Some(enumeration)
+    }
+}
+
+impl From<&Error> for CtlError {
+    fn from(e: &Error) -> Self {
+        if let Some(cause) = e.kind::<CtlError>() {
+            cause
+        } else if let Some(error) = e.kind::<FileError>() {
+            match error {
+                FileError::Inval | FileError::Nosys | FileError::Noent | FileError::Nxio => {
+                    CtlError::UnsupportedValue
+                }
+                FileError::Again | FileError::Txtbsy => CtlError::DeviceBusy,
+                FileError::Nodev => CtlError::Disconnected,
+                _ => CtlError::Io,
+            }
+        } else if let Some(error) = e.kind::<SndUnitError>() {
+            match error {
+                SndUnitError::Used | SndUnitError::Locked => CtlError::DeviceBusy,
+                SndUnitError::Disconnected => CtlError::Disconnected,
+                _ => CtlError::Io,
+            }
+        } else if let Some(GeneralProtocolError::Timeout) = e.kind::<GeneralProtocolError>() {
+            CtlError::Timeout
+        } else if let Some(FwNodeError::Disconnected) = e.kind::<FwNodeError>() {
+            CtlError::Disconnected
+        } else {
+            CtlError::Io
+        }
+    }
+}
+
+/// Convert the error into the domain of control error with the same message, so that callers
+/// can match the cause of failure.
+pub fn map_ctl_error(e: Error) -> Error {
+    if e.kind::<CtlError>().is_some() {
+        e
+    } else {
+        Error::new(CtlError::from(&e), &e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cause_from_domain() {
+        let e = Error::new(FileError::Inval, "");
+        assert_eq!(CtlError::from(&e), CtlError::UnsupportedValue);
+
+        let e = Error::new(FileError::Nxio, "");
+        assert_eq!(CtlError::from(&e), CtlError::UnsupportedValue);
+
+        let e = Error::new(FileError::Failed, "");
+        assert_eq!(CtlError::from(&e), CtlError::Io);
+
+        let e = Error::new(SndUnitError::Locked, "");
+        assert_eq!(CtlError::from(&e), CtlError::DeviceBusy);
+
+        let e = Error::new(GeneralProtocolError::Timeout, "");
+        assert_eq!(CtlError::from(&e), CtlError::Timeout);
+
+        let e = Error::new(GeneralProtocolError::Global, "");
+        assert_eq!(CtlError::from(&e), CtlError::Io);
+
+        let e = Error::new(CtlError::Disconnected, "");
+        assert_eq!(CtlError::from(&e), CtlError::Disconnected);
+    }
+
+    #[test]
+    fn map_with_message() {
+        let e = map_ctl_error(Error::new(GeneralProtocolError::Timeout, "no response"));
+        assert_eq!(e.kind::<CtlError>(), Some(CtlError::Timeout));
+        assert!(e.to_string().contains("no response"));
+    }
+}
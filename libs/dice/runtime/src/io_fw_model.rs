@@ -15,6 +15,7 @@ use dice_protocols::tcat::{*, global_section::*, tx_stream_format_section::*};
 use dice_protocols::alesis::{meter::*, mixer::*, output::*};
 
 use crate::common_ctl::*;
+use crate::meter_scale::*;
 
 #[derive(Default)]
 pub struct IoFwModel{
@@ -238,6 +239,13 @@ impl MeterCtl {
     const LEVEL_STEP: i32 = 0x100;
     const LEVEL_TLV: DbInterval = DbInterval{min: -9000, max: 0, linear: false, mute_avail: false};
 
+    const METER_SCALE: MeterScale = MeterScale{
+        min: Self::LEVEL_MIN,
+        max: Self::LEVEL_MAX,
+        step: Self::LEVEL_STEP,
+        tlv: Self::LEVEL_TLV,
+    };
+
     fn load(&mut self, card_cntr: &mut CardCntr, unit: &SndDice, proto: &FwReq, state: &mut AlesisIoFwState,
             timeout_ms: u32)
         -> Result<(), Error>
@@ -247,23 +255,19 @@ impl MeterCtl {
         let m = AsRef::<IoMeter>::as_ref(state);
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::ANALOG_INPUT_METER_NAME, 0);
-        card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                m.analog_inputs.len(), Some(&Vec::<u32>::from(Self::LEVEL_TLV)), false)
+        Self::METER_SCALE.add_int_elems(card_cntr, &elem_id, 1, m.analog_inputs.len())
             .map(|mut elem_id_list| self.0.append(&mut elem_id_list))?;
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::DIGITAL_A_INPUT_METER_NAME, 0);
-        card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                m.digital_a_inputs.len(), Some(&Vec::<u32>::from(Self::LEVEL_TLV)), false)
+        Self::METER_SCALE.add_int_elems(card_cntr, &elem_id, 1, m.digital_a_inputs.len())
             .map(|mut elem_id_list| self.0.append(&mut elem_id_list))?;
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::DIGITAL_B_INPUT_METER_NAME, 0);
-        card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                m.digital_b_inputs.len(), Some(&Vec::<u32>::from(Self::LEVEL_TLV)), false)
+        Self::METER_SCALE.add_int_elems(card_cntr, &elem_id, 1, m.digital_b_inputs.len())
             .map(|mut elem_id_list| self.0.append(&mut elem_id_list))?;
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_OUT_METER_NAME, 0);
-        card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                m.mixer_outputs.len(), Some(&Vec::<u32>::from(Self::LEVEL_TLV)), false)
+        Self::METER_SCALE.add_int_elems(card_cntr, &elem_id, 1, m.mixer_outputs.len())
             .map(|mut elem_id_list| self.0.append(&mut elem_id_list))?;
 
         Ok(())
@@ -281,22 +285,22 @@ impl MeterCtl {
         match elem_id.get_name().as_str() {
             Self::ANALOG_INPUT_METER_NAME => {
                 let m = AsRef::<IoMeter>::as_ref(state);
-                elem_value.set_int(&m.analog_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &m.analog_inputs);
                 Ok(true)
             }
             Self::DIGITAL_A_INPUT_METER_NAME => {
                 let m = AsRef::<IoMeter>::as_ref(state);
-                elem_value.set_int(&m.digital_a_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &m.digital_a_inputs);
                 Ok(true)
             }
             Self::DIGITAL_B_INPUT_METER_NAME => {
                 let m = AsRef::<IoMeter>::as_ref(state);
-                elem_value.set_int(&m.digital_b_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &m.digital_b_inputs);
                 Ok(true)
             }
             Self::MIXER_OUT_METER_NAME => {
                 let m = AsRef::<IoMeter>::as_ref(state);
-                elem_value.set_int(&m.mixer_outputs);
+                Self::METER_SCALE.set_vals(elem_value, &m.mixer_outputs);
                 Ok(true)
             }
             _ => Ok(false),
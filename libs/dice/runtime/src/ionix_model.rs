@@ -2,7 +2,7 @@
 // Copyright (c) 2021 Takashi Sakamoto
 use glib::Error;
 
-use alsactl::{ElemId, ElemIfaceType, ElemValue, ElemValueExt};
+use alsactl::{ElemId, ElemIfaceType, ElemValue};
 
 use hinawa::FwReq;
 use hinawa::{SndDice, SndUnitExt};
@@ -16,6 +16,7 @@ use dice_protocols::tcat::{*, global_section::*};
 use dice_protocols::lexicon::{meter::*, mixer::*};
 
 use crate::common_ctl::*;
+use crate::meter_scale::*;
 
 #[derive(Default)]
 pub struct IonixModel{
@@ -127,6 +128,13 @@ impl MeterCtl {
     const LEVEL_STEP: i32 = 1;
     const LEVEL_TLV: DbInterval = DbInterval{min: -6000, max: 0, linear: false, mute_avail: false};
 
+    const METER_SCALE: MeterScale = MeterScale{
+        min: Self::LEVEL_MIN,
+        max: Self::LEVEL_MAX,
+        step: Self::LEVEL_STEP,
+        tlv: Self::LEVEL_TLV,
+    };
+
     fn load(&mut self, card_cntr: &mut CardCntr) -> Result<(), Error> {
         [
             (Self::SPDIF_INPUT_NAME, 2),
@@ -137,8 +145,7 @@ impl MeterCtl {
         ].iter()
             .try_for_each(|&(name, count)| {
                 let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, name, 0);
-                card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                        count, Some(&Vec::<u32>::from(Self::LEVEL_TLV)), false)
+                Self::METER_SCALE.add_int_elems(card_cntr, &elem_id, 1, count)
                     .map(|mut elem_id_list| self.measured_elem_list.append(&mut elem_id_list))
             })?;
 
@@ -152,23 +159,23 @@ impl MeterCtl {
     fn read_measured_elem(&self, elem_id: &ElemId, elem_value: &ElemValue) -> Result<bool, Error> {
         match elem_id.get_name().as_str() {
             Self::SPDIF_INPUT_NAME => {
-                elem_value.set_int(&self.meters.spdif_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &self.meters.spdif_inputs);
                 Ok(true)
             }
             Self::STREAM_INPUT_NAME => {
-                elem_value.set_int(&self.meters.stream_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &self.meters.stream_inputs);
                 Ok(true)
             }
             Self::ANALOG_INPUT_NAME => {
-                elem_value.set_int(&self.meters.analog_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &self.meters.analog_inputs);
                 Ok(true)
             }
             Self::BUS_OUTPUT_NAME => {
-                elem_value.set_int(&self.meters.bus_outputs);
+                Self::METER_SCALE.set_vals(elem_value, &self.meters.bus_outputs);
                 Ok(true)
             }
             Self::MAIN_OUTPUT_NAME => {
-                elem_value.set_int(&self.meters.main_outputs);
+                Self::METER_SCALE.set_vals(elem_value, &self.meters.main_outputs);
                 Ok(true)
             }
             _ => Ok(false),
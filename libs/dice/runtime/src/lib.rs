@@ -3,12 +3,15 @@
 mod model;
 
 mod common_ctl;
+mod bus_reset_ctl;
+pub mod ctl_error;
 mod minimal_model;
 mod tcelectronic;
 mod io_fw_model;
 mod ionix_model;
 mod presonus;
 
+mod meter_scale;
 mod tcd22xx_ctl;
 mod extension_model;
 mod pfire_model;
@@ -23,16 +26,20 @@ use nix::sys::signal;
 
 use std::sync::mpsc;
 
-use hinawa::FwNodeExt;
+use hinawa::{FwNodeExt, FwReq};
 use hinawa::{SndDice, SndDiceExt, SndUnitExt};
 
+use dice_protocols::tcat::GeneralProtocol;
+
 use alsactl::{CardExt, CardExtManual, ElemValueExtManual};
 
 use core::RuntimeOperation;
 use core::dispatcher;
 use core::card_cntr;
+use core::card_cntr::CtlModel;
 
 use model::DiceModel;
+use bus_reset_ctl::BusResetCtl;
 
 enum Event {
     Shutdown,
@@ -51,6 +58,8 @@ pub struct DiceRuntime{
     tx: mpsc::SyncSender<Event>,
     dispatchers: Vec<dispatcher::Dispatcher>,
     timer: Option<dispatcher::Dispatcher>,
+    meter_frozen: bool,
+    bus_reset_ctl: BusResetCtl,
 }
 
 impl RuntimeOperation<u32> for DiceRuntime {
@@ -71,7 +80,11 @@ impl RuntimeOperation<u32> for DiceRuntime {
 
         let timer = None;
 
-        Ok(DiceRuntime{unit, model, card_cntr, rx, tx, dispatchers, timer})
+        let meter_frozen = false;
+
+        let bus_reset_ctl = BusResetCtl::default();
+
+        Ok(DiceRuntime{unit, model, card_cntr, rx, tx, dispatchers, timer, meter_frozen, bus_reset_ctl})
     }
 
     fn listen(&mut self) -> Result<(), Error> {
@@ -84,8 +97,14 @@ impl RuntimeOperation<u32> for DiceRuntime {
             let elem_id = alsactl::ElemId::new_by_name(alsactl::ElemIfaceType::Mixer, 0, 0,
                                                        Self::TIMER_NAME, 0);
             let _ = self.card_cntr.add_bool_elems(&elem_id, 1, 1, true)?;
+
+            let elem_id = alsactl::ElemId::new_by_name(alsactl::ElemIfaceType::Mixer, 0, 0,
+                                                       Self::METER_FREEZE_NAME, 0);
+            let _ = self.card_cntr.add_bool_elems(&elem_id, 1, 1, true)?;
         }
 
+        self.bus_reset_ctl.load(&mut self.unit, &mut self.card_cntr)?;
+
         Ok(())
     }
 
@@ -94,15 +113,17 @@ impl RuntimeOperation<u32> for DiceRuntime {
             if let Ok(ev) = self.rx.recv() {
                 match ev {
                     Event::Shutdown => break,
-                    Event::Disconnected => break,
+                    Event::Disconnected => return Err(core::node_disconnected_error()),
                     Event::BusReset(generation) => {
                         println!("IEEE 1394 bus is updated: {}", generation);
+                        let elem_id_list = self.bus_reset_ctl.notified_elem_list.clone();
+                        let _ = self.card_cntr.dispatch_notification(&mut self.unit, &generation,
+                                                                     &elem_id_list,
+                                                                     &mut self.bus_reset_ctl);
                     }
                     Event::Elem(elem_id, events) => {
-                        if elem_id.get_name() != Self::TIMER_NAME {
-                            let _ = self.model.dispatch_elem_event(&mut self.unit, &mut self.card_cntr,
-                                                                   &elem_id, &events);
-                        } else {
+                        let name = elem_id.get_name();
+                        if name == Self::TIMER_NAME {
                             let mut elem_value = alsactl::ElemValue::new();
                             let _ = self.card_cntr.card.read_elem_value(&elem_id, &mut elem_value)
                                 .map(|_| {
@@ -114,19 +135,91 @@ impl RuntimeOperation<u32> for DiceRuntime {
                                         self.stop_interval_timer();
                                     }
                                 });
+                        } else if name == Self::METER_FREEZE_NAME {
+                            let mut elem_value = alsactl::ElemValue::new();
+                            let _ = self.card_cntr.card.read_elem_value(&elem_id, &mut elem_value)
+                                .map(|_| {
+                                    let mut vals = [false];
+                                    elem_value.get_bool(&mut vals);
+                                    self.meter_frozen = vals[0];
+                                });
+                        } else {
+                            let _ = self.model.dispatch_elem_event(&mut self.unit, &mut self.card_cntr,
+                                                                   &elem_id, &events)
+                                .map_err(|e| eprintln!("{}: {}", name, e));
                         }
                     }
                     Event::Notify(msg) => {
                         let _ = self.model.dispatch_msg(&mut self.unit, &mut self.card_cntr, msg);
                     }
                     Event::Timer => {
-                        let _ = self.model.measure_elems(&mut self.unit, &mut self.card_cntr);
+                        // Keep the last values of measured elements while frozen.
+                        if !self.meter_frozen {
+                            let _ = self.model.measure_elems(&mut self.unit, &mut self.card_cntr);
+                        }
                     }
                 }
             }
         }
         Ok(())
     }
+
+    fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error> {
+        Ok(self.card_cntr.get_elem_list())
+    }
+
+    fn dump(&mut self) -> Result<(), Error> {
+        // The node should be dispatched to receive response of transaction. The other events are
+        // not handled.
+        let name = Self::NODE_DISPATCHER_NAME.to_string();
+        let mut dispatcher = dispatcher::Dispatcher::run(name)?;
+        dispatcher.attach_fw_node(&self.unit.get_node(), |_| {})?;
+        self.dispatchers.push(dispatcher);
+
+        let proto = DumpProto::default();
+        let node = self.unit.get_node();
+
+        let sections = proto.read_general_sections(&node, Self::DUMP_TIMEOUT_MS)?;
+        let mut ranges: Vec<(&str, usize, usize)> = [
+            ("global", sections.global),
+            ("tx-stream-format", sections.tx_stream_format),
+            ("rx-stream-format", sections.rx_stream_format),
+            ("ext-sync", sections.ext_sync),
+        ].iter()
+            .filter(|(_, section)| section.size > 0)
+            .map(|&(name, section)| (name, section.offset, section.size))
+            .collect();
+        ranges.append(&mut self.model.get_dump_ranges());
+
+        ranges.iter()
+            .try_for_each(|&(name, offset, size)| {
+                let mut raw = vec![0;size];
+                proto.read(&node, offset, &mut raw, Self::DUMP_TIMEOUT_MS)
+                    .map(|_| print_hexdump(name, offset, &raw))
+            })
+    }
+}
+
+#[derive(Default)]
+struct DumpProto(FwReq);
+
+impl AsRef<FwReq> for DumpProto {
+    fn as_ref(&self) -> &FwReq {
+        &self.0
+    }
+}
+
+fn print_hexdump(name: &str, offset: usize, raw: &[u8]) {
+    println!("{} section:", name);
+    raw.chunks(16)
+        .enumerate()
+        .for_each(|(i, chunk)| {
+            let bytes = chunk.iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<_>>()
+                .join(" ");
+            println!("  {:08x}: {}", offset + i * 16, bytes);
+        });
 }
 
 impl Drop for DiceRuntime {
@@ -150,7 +243,9 @@ impl DiceRuntime {
     const TIMER_DISPATCHER_NAME: &'static str = "interval timer dispatcher";
 
     const TIMER_NAME: &'static str = "metering";
+    const METER_FREEZE_NAME: &'static str = "meter-freeze";
     const TIMER_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+    const DUMP_TIMEOUT_MS: u32 = 100;
 
     fn launch_node_event_dispatcher(&mut self) -> Result<(), Error> {
         let name = Self::NODE_DISPATCHER_NAME.to_string();
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2020 Takashi Sakamoto
+
+//! Scaling for level of meters.
+//!
+//! The level of meters is reported by integer value in 0.01 dBFS unit between -96.00 and 0.00, with
+//! the same data of TLV for all of models, so that applications can interpret the value from any
+//! model uniformly. The value in native range of model is reported instead when the crate is
+//! built with `raw-meter` feature.
+
+use glib::Error;
+
+use alsactl::{ElemId, ElemValue, ElemValueExt};
+
+use alsa_ctl_tlv_codec::items::DbInterval;
+
+use core::card_cntr::*;
+
+const RAW_METER: bool = cfg!(feature = "raw-meter");
+
+/// The structure to represent native range of meter and its representation by dB.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MeterScale {
+    pub min: i32,
+    pub max: i32,
+    pub step: i32,
+    pub tlv: DbInterval,
+}
+
+impl MeterScale {
+    pub const LEVEL_MIN: i32 = -9600;
+    pub const LEVEL_MAX: i32 = 0;
+    pub const LEVEL_STEP: i32 = 1;
+    pub const LEVEL_TLV: DbInterval = DbInterval{min: -9600, max: 0, linear: false, mute_avail: false};
+
+    pub fn add_int_elems(&self, card_cntr: &mut CardCntr, elem_id: &ElemId, elem_count: usize,
+                         value_count: usize)
+        -> Result<Vec<ElemId>, Error>
+    {
+        let (min, max, step, tlv) = if RAW_METER {
+            (self.min, self.max, self.step, self.tlv)
+        } else {
+            (Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP, Self::LEVEL_TLV)
+        };
+        card_cntr.add_int_elems(elem_id, elem_count, min, max, step, value_count,
+                                Some(&Into::<Vec<u32>>::into(tlv)), false)
+    }
+
+    /// Convert the value in native range into the value in 0.01 dBFS unit.
+    pub fn convert(&self, val: i32) -> i32 {
+        if RAW_METER {
+            return val;
+        }
+
+        let val = std::cmp::max(self.min, std::cmp::min(self.max, val));
+        let db = self.tlv.min as i64 +
+                 (val - self.min) as i64 * (self.tlv.max - self.tlv.min) as i64 /
+                 (self.max - self.min) as i64;
+        std::cmp::max(Self::LEVEL_MIN as i64, std::cmp::min(Self::LEVEL_MAX as i64, db)) as i32
+    }
+
+    pub fn set_vals(&self, elem_value: &ElemValue, vals: &[i32]) {
+        let vals = vals.iter()
+            .map(|&val| self.convert(val))
+            .collect::<Vec<i32>>();
+        elem_value.set_int(&vals);
+    }
+}
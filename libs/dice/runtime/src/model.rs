@@ -12,6 +12,7 @@ use dice_protocols::tcat::{*, config_rom::*, extension::*};
 
 use std::convert::TryFrom;
 
+use super::ctl_error::map_ctl_error;
 use super::minimal_model::MinimalModel;
 use super::tcelectronic::k24d_model::*;
 use super::tcelectronic::k8_model::*;
@@ -19,6 +20,7 @@ use super::tcelectronic::studiok48_model::*;
 use super::tcelectronic::klive_model::*;
 use super::tcelectronic::desktopk6_model::*;
 use super::tcelectronic::itwin_model::*;
+use super::tcelectronic::TcKonnektModel;
 use super::io_fw_model::*;
 use super::ionix_model::*;
 use super::presonus::fstudio_model::*;
@@ -197,11 +199,24 @@ impl DiceModel {
         Ok(())
     }
 
+    /// Retrieve the list of ranges of registers specific to the model, to dump raw content.
+    pub fn get_dump_ranges(&self) -> Vec<(&'static str, usize, usize)> {
+        match &self.model {
+            Model::TcK24d(m) => m.get_dump_ranges(),
+            Model::TcK8(m) => m.get_dump_ranges(),
+            Model::TcStudiok48(m) => m.get_dump_ranges(),
+            Model::TcKlive(m) => m.get_dump_ranges(),
+            Model::TcDesktopk6(m) => m.get_dump_ranges(),
+            Model::TcItwin(m) => m.get_dump_ranges(),
+            _ => Vec::new(),
+        }
+    }
+
     pub fn dispatch_elem_event(&mut self, unit: &mut SndDice, card_cntr: &mut CardCntr,
                                elem_id: &alsactl::ElemId, events: &alsactl::ElemEventMask)
         -> Result<(), Error>
     {
-        match &mut self.model {
+        let res = match &mut self.model {
             Model::Minimal(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
             Model::TcK24d(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
             Model::TcK8(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
@@ -222,7 +237,9 @@ impl DiceModel {
             Model::FocusriteSPro26(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
             Model::PresonusFStudioProject(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
             Model::PresonusFStudioMobile(m) => card_cntr.dispatch_elem_event(unit, &elem_id, &events, m),
-        }
+        };
+        // The failure of write operation is reported with the cause in domain of control error.
+        res.map_err(map_ctl_error)
     }
 
     pub fn dispatch_msg(&mut self, unit: &mut SndDice, card_cntr: &mut CardCntr, msg: u32)
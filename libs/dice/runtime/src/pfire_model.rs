@@ -145,21 +145,19 @@ impl<S> MeasureModel<hinawa::SndDice> for PfireModel<S>
 }
 
 #[derive(Default, Debug)]
-struct SpecificCtl{
-    targets: [bool;KNOB_COUNT],
-}
+struct SpecificCtl;
 
 impl SpecificCtl {
-    const MASTER_KNOB_NAME: &'static str = "master-knob-target";
     const OPT_IFACE_B_MODE_NAME: &'static str = "optical-iface-b-mode";
     const STANDALONE_CONVERTER_MODE_NAME: &'static str = "standalone-converter-mode";
 
-    // MEMO: Both models support 'Output{id: DstBlkId::Ins0, count: 8}'.
-    const MASTER_KNOB_TARGET_LABELS: [&'static str;4] = [
-        "analog-out-1/2",
-        "analog-out-3/4",
-        "analog-out-5/6",
-        "analog-out-7/8",
+    // MEMO: Both models support 'Output{id: DstBlkId::Ins0, count: 8}', thus each bit of knob
+    // assignment corresponds to the same pair of analog outputs.
+    const MASTER_KNOB_ASSIGN_NAMES: [&'static str;KNOB_COUNT] = [
+        "master-knob-assign-analog-out-1/2",
+        "master-knob-assign-analog-out-3/4",
+        "master-knob-assign-analog-out-5/6",
+        "master-knob-assign-analog-out-7/8",
     ];
     const OPT_IFACE_B_MODE_LABELS: [&'static str;2] = ["ADAT", "S/PDIF"];
     const STANDALONE_CONVERTER_MODE_LABELS: [&'static str;2] = ["A/D-D/A", "A/D-only"];
@@ -167,8 +165,12 @@ impl SpecificCtl {
     fn load(&self, caps: &ClockCaps, src_labels: &ClockSourceLabels, card_cntr: &mut CardCntr)
         -> Result<(), Error>
     {
-        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::MASTER_KNOB_NAME, 0);
-        let _ = card_cntr.add_bool_elems(&elem_id, 1, Self::MASTER_KNOB_TARGET_LABELS.len(), true)?;
+        Self::MASTER_KNOB_ASSIGN_NAMES.iter()
+            .try_for_each(|name| {
+                let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, name, 0);
+                card_cntr.add_bool_elems(&elem_id, 1, 1, true)
+                    .map(|_| ())
+            })?;
 
         // NOTE: ClockSource::Tdif is used for second optical interface as 'ADAT_AUX'.
         if ClockSource::Tdif.is_supported(caps, src_labels) {
@@ -186,13 +188,15 @@ impl SpecificCtl {
             elem_value: &ElemValue, timeout_ms: u32)
         -> Result<bool, Error>
     {
-        match elem_id.get_name().as_str() {
-            Self::MASTER_KNOB_NAME => {
-                let mut assigns = [false;KNOB_COUNT];
-                proto.read_knob_assign(&unit.get_node(), sections, &mut assigns, timeout_ms)?;
-                elem_value.set_bool(&self.targets);
-                Ok(true)
-            }
+        let name = elem_id.get_name();
+        if let Some(idx) = Self::MASTER_KNOB_ASSIGN_NAMES.iter().position(|&n| n == name.as_str()) {
+            let mut assigns = [false;KNOB_COUNT];
+            proto.read_knob_assign(&unit.get_node(), sections, &mut assigns, timeout_ms)?;
+            elem_value.set_bool(&[assigns[idx]]);
+            return Ok(true);
+        }
+
+        match name.as_str() {
             Self::OPT_IFACE_B_MODE_NAME => {
                 ElemValueAccessor::<u32>::set_val(elem_value, || {
                     proto.read_opt_iface_b_mode(&unit.get_node(), sections, timeout_ms)
@@ -222,19 +226,22 @@ impl SpecificCtl {
     }
 
     fn write(&mut self, unit: &SndDice, proto: &FwReq, sections: &ExtensionSections,
-             elem_id: &ElemId, old: &ElemValue, new: &ElemValue, timeout_ms: u32)
+             elem_id: &ElemId, _: &ElemValue, new: &ElemValue, timeout_ms: u32)
         -> Result<bool, Error>
     {
-        match elem_id.get_name().as_str() {
-            Self::MASTER_KNOB_NAME => {
-                ElemValueAccessor::<bool>::get_vals(new, old, self.targets.len(), |idx, val| {
-                    self.targets[idx] = val;
-                    Ok(())
-                })?;
+        let name = elem_id.get_name();
+        if let Some(idx) = Self::MASTER_KNOB_ASSIGN_NAMES.iter().position(|&n| n == name.as_str()) {
+            return ElemValueAccessor::<bool>::get_val(new, |val| {
                 let node = unit.get_node();
-                proto.write_knob_assign(&node, sections, &self.targets, timeout_ms)?;
-                Ok(true)
-            }
+                let mut assigns = [false;KNOB_COUNT];
+                proto.read_knob_assign(&node, sections, &mut assigns, timeout_ms)?;
+                assigns[idx] = val;
+                proto.write_knob_assign(&node, sections, &assigns, timeout_ms)
+            })
+            .map(|_| true);
+        }
+
+        match name.as_str() {
             Self::OPT_IFACE_B_MODE_NAME => {
                 ElemValueAccessor::<u32>::get_val(new, |val| {
                     let mode = match val {
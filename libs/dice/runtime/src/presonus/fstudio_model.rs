@@ -16,6 +16,7 @@ use dice_protocols::tcat::global_section::*;
 use dice_protocols::presonus::fstudio::*;
 
 use crate::common_ctl::*;
+use crate::meter_scale::*;
 
 #[derive(Default)]
 pub struct FStudioModel{
@@ -157,6 +158,13 @@ impl MeterCtl {
     const LEVEL_STEP: i32 = 1;
     const LEVEL_TLV: DbInterval = DbInterval{min: -9600, max: 0, linear: false, mute_avail: false};
 
+    const METER_SCALE: MeterScale = MeterScale{
+        min: Self::LEVEL_MIN,
+        max: Self::LEVEL_MAX,
+        step: Self::LEVEL_STEP,
+        tlv: Self::LEVEL_TLV,
+    };
+
     fn load(&mut self, card_cntr: &mut CardCntr, unit: &SndDice, proto: &FStudioProto, timeout_ms: u32)
         -> Result<(), Error>
     {
@@ -169,8 +177,7 @@ impl MeterCtl {
         ].iter()
             .try_for_each(|&(name, count)| {
                 let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, name, 0);
-                card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                        count, Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)), false)
+                Self::METER_SCALE.add_int_elems(card_cntr, &elem_id, 1, count)
                     .map(|mut elem_id_list| self.measured_elem_list.append(&mut elem_id_list))
             })?;
 
@@ -191,21 +198,21 @@ impl MeterCtl {
                 let vals: Vec<i32> = self.meter.analog_inputs.iter()
                     .map(|&l| l as i32)
                     .collect();
-                elem_value.set_int(&vals);
+                Self::METER_SCALE.set_vals(elem_value, &vals);
                 Ok(true)
             }
             Self::STREAM_INPUT_NAME => {
                 let vals: Vec<i32> = self.meter.stream_inputs.iter()
                     .map(|&l| l as i32)
                     .collect();
-                elem_value.set_int(&vals);
+                Self::METER_SCALE.set_vals(elem_value, &vals);
                 Ok(true)
             }
             Self::MIXER_OUTPUT_NAME => {
                 let vals: Vec<i32> = self.meter.mixer_outputs.iter()
                     .map(|&l| l as i32)
                     .collect();
-                elem_value.set_int(&vals);
+                Self::METER_SCALE.set_vals(elem_value, &vals);
                 Ok(true)
             }
             _ => Ok(false),
@@ -18,6 +18,8 @@ use dice_protocols::tcat::extension::peak_section::*;
 use dice_protocols::tcat::extension::{current_config_section::*, standalone_section::*};
 use dice_protocols::tcat::tcd22xx_spec::*;
 
+use std::collections::VecDeque;
+
 #[derive(Default, Debug)]
 pub struct Tcd22xxCtl<S>
     where S: Tcd22xxSpec + AsRef<Tcd22xxState> + AsMut<Tcd22xxState>,
@@ -28,6 +30,7 @@ pub struct Tcd22xxCtl<S>
     router_ctl: RouterCtl,
     mixer_ctl: MixerCtl,
     standalone_ctl: StandaloneCtl,
+    stream_format_ctl: StreamFormatCtl,
 }
 
 impl<S> Tcd22xxCtl<S>
@@ -45,6 +48,7 @@ impl<S> Tcd22xxCtl<S>
         self.router_ctl.load(&node, proto, sections, &self.caps, &self.state, caps, timeout_ms, card_cntr)?;
         self.mixer_ctl.load(&self.caps, &self.state, card_cntr)?;
         self.standalone_ctl.load(caps, src_labels, card_cntr)?;
+        self.stream_format_ctl.load(&node, proto, sections, &self.caps, caps, timeout_ms, card_cntr)?;
 
         Ok(())
     }
@@ -64,13 +68,17 @@ impl<S> Tcd22xxCtl<S>
                 elem_value: &mut ElemValue, timeout_ms: u32)
         -> Result<bool, Error>
     {
-        if self.router_ctl.read(&self.state, elem_id, elem_value)? {
+        if self.meter_ctl.read(elem_id, elem_value)? {
+            Ok(true)
+        } else if self.router_ctl.read(&self.state, elem_id, elem_value)? {
             Ok(true)
         } else if self.mixer_ctl.read(&self.state, elem_id, elem_value)? {
             Ok(true)
         } else if self.standalone_ctl.read(&unit.get_node(), proto, sections, elem_id, elem_value,
                                            timeout_ms)? {
             Ok(true)
+        } else if self.stream_format_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -82,8 +90,10 @@ impl<S> Tcd22xxCtl<S>
     {
         let node = unit.get_node();
 
-        if self.router_ctl.write(&node, proto, sections, &self.caps, &mut self.state, elem_id,
-                              old, new, timeout_ms)? {
+        if self.meter_ctl.write(elem_id, new)? {
+            Ok(true)
+        } else if self.router_ctl.write(&node, proto, sections, &self.caps, &mut self.state, elem_id,
+                                     old, new, timeout_ms)? {
             Ok(true)
         } else if self.mixer_ctl.write(&node, proto, sections, &self.caps, &mut self.state, elem_id,
                                        old, new, timeout_ms)? {
@@ -137,6 +147,19 @@ impl<S> Tcd22xxCtl<S>
     }
 }
 
+/// The mode of ballistics for meters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum MeterMode {
+    Peak,
+    Rms,
+}
+
+impl Default for MeterMode {
+    fn default() -> Self {
+        Self::Peak
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct MeterCtl {
     // Maximum number block at low rate mode.
@@ -150,6 +173,10 @@ pub struct MeterCtl {
 
     out_sat: Vec<bool>,
 
+    mode: MeterMode,
+    // The peaks in recent measurements for RMS mode, from the oldest.
+    peak_history: VecDeque<Vec<i32>>,
+
     measured_elem_list: Vec<alsactl::ElemId>,
 }
 
@@ -158,11 +185,18 @@ impl MeterCtl {
     const STREAM_TX_METER_NAME: &'static str = "stream-source-meter";
     const MIXER_INPUT_METER_NAME: &'static str = "mixer-source-meter";
     const INPUT_SATURATION_NAME: &'static str = "mixer-out-saturation";
+    const METER_MODE_NAME: &'static str = "meter-mode";
 
     const COEF_MIN: i32 = 0;
     const COEF_MAX: i32 = 0x00000fffi32; // Upper 12 bits of each sample.
     const COEF_STEP: i32 = 1;
 
+    const METER_MODES: [MeterMode;2] = [MeterMode::Peak, MeterMode::Rms];
+    const METER_MODE_LABELS: [&'static str;2] = ["Peak", "RMS"];
+
+    // About 300 msec with 50 msec interval of measurement.
+    const RMS_WINDOW_SIZE: usize = 6;
+
     pub fn load<T>(&mut self, node: &FwNode, proto: &FwReq, sections: &ExtensionSections,
                    caps: &ExtensionCaps, state: &T, timeout_ms: u32, card_cntr: &mut CardCntr)
         -> Result<(), Error>
@@ -176,7 +210,8 @@ impl MeterCtl {
 
         let (tx_entries, rx_entries) =
             proto.read_current_stream_format_entries(&node, sections, caps, RateMode::Low, timeout_ms)?;
-        let (_, stream_blk_dsts) = state.compute_avail_stream_blk_pair(&tx_entries, &rx_entries);
+        let (_, stream_blk_dsts) = state.compute_avail_stream_blk_pair(&tx_entries, &rx_entries,
+                                                                       RateMode::Low);
         self.stream_blk_dsts = stream_blk_dsts;
         let mut elem_id_list = Self::add_an_elem_for_meter(card_cntr, Self::STREAM_TX_METER_NAME,
                                                            &self.stream_blk_dsts)?;
@@ -196,9 +231,49 @@ impl MeterCtl {
         let mut elem_id_list = card_cntr.add_bool_elems(&elem_id, 1, self.mixer_blk_dsts.len(), false)?;
         self.measured_elem_list.append(&mut elem_id_list);
 
+        let elem_id = alsactl::ElemId::new_by_name(alsactl::ElemIfaceType::Mixer,
+                                                   0, 0, Self::METER_MODE_NAME, 0);
+        let _ = card_cntr.add_enum_elems(&elem_id, 1, 1, &Self::METER_MODE_LABELS, None, true)?;
+
         Ok(())
     }
 
+    pub fn read(&self, elem_id: &ElemId, elem_value: &mut ElemValue) -> Result<bool, Error> {
+        match elem_id.get_name().as_str() {
+            Self::METER_MODE_NAME => {
+                ElemValueAccessor::<u32>::set_val(elem_value, || {
+                    let pos = Self::METER_MODES.iter()
+                        .position(|&m| m == self.mode)
+                        .unwrap();
+                    Ok(pos as u32)
+                })
+                .map(|_| true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    pub fn write(&mut self, elem_id: &ElemId, elem_value: &ElemValue) -> Result<bool, Error> {
+        match elem_id.get_name().as_str() {
+            Self::METER_MODE_NAME => {
+                ElemValueAccessor::<u32>::get_val(elem_value, |val| {
+                    Self::METER_MODES.iter()
+                        .nth(val as usize)
+                        .ok_or_else(|| {
+                            let msg = format!("Invalid value for index of meter mode: {}", val);
+                            Error::new(FileError::Inval, &msg)
+                        })
+                        .map(|&m| {
+                            self.mode = m;
+                            self.peak_history.clear();
+                        })
+                })
+                .map(|_| true)
+            }
+            _ => Ok(false),
+        }
+    }
+
     fn add_an_elem_for_meter(card_cntr: &mut CardCntr, label: &str, targets: &Vec<DstBlk>)
         -> Result<Vec<ElemId>, Error>
     {
@@ -215,20 +290,52 @@ impl MeterCtl {
     {
         let entries = proto.read_peak_entries(&node, sections, caps, timeout_ms)?;
 
-        self.real_meter.iter_mut().chain(self.stream_meter.iter_mut()).chain(self.mixer_meter.iter_mut())
-            .zip(self.real_blk_dsts.iter().chain(self.stream_blk_dsts.iter()).chain(self.mixer_blk_dsts.iter()))
-            .for_each(|(val, dst)| {
-                *val = entries.iter()
+        let peaks: Vec<i32> = self.real_blk_dsts.iter()
+            .chain(self.stream_blk_dsts.iter())
+            .chain(self.mixer_blk_dsts.iter())
+            .map(|dst| {
+                entries.iter()
                     .find(|entry| entry.dst.eq(dst))
                     .map(|entry| entry.peak as i32)
-                    .unwrap_or(0);
-            });
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let vals = match self.mode {
+            MeterMode::Peak => peaks,
+            MeterMode::Rms => self.push_peaks(peaks),
+        };
+
+        self.real_meter.iter_mut().chain(self.stream_meter.iter_mut()).chain(self.mixer_meter.iter_mut())
+            .zip(vals.iter())
+            .for_each(|(val, &v)| *val = v);
 
         self.out_sat = proto.read_saturation(&node, sections, caps, timeout_ms)?;
 
         Ok(())
     }
 
+    // Keep the peaks in the window from the oldest, then compute RMS of them.
+    fn push_peaks(&mut self, peaks: Vec<i32>) -> Vec<i32> {
+        if self.peak_history.len() >= Self::RMS_WINDOW_SIZE {
+            self.peak_history.pop_front();
+        }
+        self.peak_history.push_back(peaks);
+        Self::compute_rms(&self.peak_history)
+    }
+
+    fn compute_rms(history: &VecDeque<Vec<i32>>) -> Vec<i32> {
+        let count = history.front().map(|peaks| peaks.len()).unwrap_or(0);
+        (0..count)
+            .map(|i| {
+                let sum = history.iter()
+                    .map(|peaks| (peaks[i] as f64).powi(2))
+                    .sum::<f64>();
+                (sum / history.len() as f64).sqrt().round() as i32
+            })
+            .collect()
+    }
+
     pub fn measure_elem(&self, elem_id: &ElemId, elem_value: &ElemValue)
         -> Result<bool, Error>
     {
@@ -268,6 +375,12 @@ impl RouterCtl {
     const CAP_SRC_NAME: &'static str = "stream-source";
     const MIXER_SRC_NAME: &'static str = "mixer-source";
 
+    // The read-only elements to expose the labels of destination for each value of the above
+    // elements, which have value per destination.
+    const OUT_DST_NAME: &'static str = "output-destination";
+    const CAP_DST_NAME: &'static str = "stream-destination";
+    const MIXER_DST_NAME: &'static str = "mixer-destination";
+
     const NONE_SRC_LABEL: &'static str = "None";
 
     pub fn load<T>(&mut self, node: &FwNode, proto: &FwReq, sections: &ExtensionSections,
@@ -292,7 +405,7 @@ impl RouterCtl {
             .try_for_each(|&m| {
                 proto.read_current_stream_format_entries(node, sections, caps, m, timeout_ms)
                     .map(|(tx, rx)| {
-                        let (tx_blk, rx_blk) = state.compute_avail_stream_blk_pair(&tx, &rx);
+                        let (tx_blk, rx_blk) = state.compute_avail_stream_blk_pair(&tx, &rx, m);
                         tx_blk.iter()
                             .for_each(|src| {
                                 if self.stream_blk_pair.0.iter().find(|s| s.eq(&src)).is_none() {
@@ -328,6 +441,10 @@ impl RouterCtl {
                                                          state)?;
         self.notified_elem_list.append(&mut elem_id_list);
 
+        Self::add_an_elem_for_dst(card_cntr, Self::OUT_DST_NAME, &self.real_blk_pair.1, state)?;
+        Self::add_an_elem_for_dst(card_cntr, Self::CAP_DST_NAME, &self.stream_blk_pair.1, state)?;
+        Self::add_an_elem_for_dst(card_cntr, Self::MIXER_DST_NAME, &self.mixer_blk_pair.1, state)?;
+
         Ok(())
     }
 
@@ -351,6 +468,18 @@ impl RouterCtl {
                                     &[&self.real_blk_pair.0, &self.stream_blk_pair.0]);
                 Ok(true)
             }
+            Self::OUT_DST_NAME => {
+                Self::read_elem_dst(elem_value, &self.real_blk_pair.1);
+                Ok(true)
+            }
+            Self::CAP_DST_NAME => {
+                Self::read_elem_dst(elem_value, &self.stream_blk_pair.1);
+                Ok(true)
+            }
+            Self::MIXER_DST_NAME => {
+                Self::read_elem_dst(elem_value, &self.mixer_blk_pair.1);
+                Ok(true)
+            }
             _ => Ok(false),
         }
     }
@@ -387,10 +516,13 @@ impl RouterCtl {
         -> Result<Vec<ElemId>, Error>
         where T: Tcd22xxSpec,
     {
-        let targets = dsts.iter().map(|&dst| state.get_dst_blk_label(dst)).collect::<Vec<String>>();
+        // The blocks are computed at low rate mode.
+        let targets = dsts.iter()
+            .map(|&dst| state.get_dst_blk_label(dst, RateMode::Low))
+            .collect::<Vec<String>>();
         let mut sources = srcs.iter()
             .flat_map(|srcs| srcs.iter())
-            .map(|src| state.get_src_blk_label(src))
+            .map(|src| state.get_src_blk_label(src, RateMode::Low))
             .collect::<Vec<String>>();
         sources.insert(0, Self::NONE_SRC_LABEL.to_string());
 
@@ -399,6 +531,25 @@ impl RouterCtl {
         Ok(elem_id_list)
     }
 
+    fn add_an_elem_for_dst<T>(card_cntr: &mut CardCntr, label: &str, dsts: &[DstBlk], state: &T)
+        -> Result<(), Error>
+        where T: Tcd22xxSpec,
+    {
+        // The blocks are computed at low rate mode.
+        let targets = dsts.iter()
+            .map(|&dst| state.get_dst_blk_label(dst, RateMode::Low))
+            .collect::<Vec<String>>();
+
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, label, 0);
+        card_cntr.add_enum_elems(&elem_id, 1, targets.len(), &targets, None, false)
+            .map(|_| ())
+    }
+
+    // The value at each index is the index of label for the destination.
+    fn read_elem_dst(elem_value: &alsactl::ElemValue, dsts: &[DstBlk]) {
+        let _ = ElemValueAccessor::<u32>::set_vals(elem_value, dsts.len(), |idx| Ok(idx as u32));
+    }
+
     fn read_elem_src<T>(state: &T, elem_value: &alsactl::ElemValue, dsts: &[DstBlk], srcs: &[&[SrcBlk]])
         where T: AsRef<Tcd22xxState>,
     {
@@ -425,6 +576,7 @@ impl RouterCtl {
         where T: Tcd22xxSpec + AsRef<Tcd22xxState> + AsMut<Tcd22xxState>,
     {
         let mut entries = state.as_ref().router_entries.clone();
+        let rate_mode = state.as_ref().get_rate_mode();
 
         ElemValueAccessor::<u32>::get_vals(new, old, dsts.len(), |idx, val| {
             let dst = dsts[idx];
@@ -442,6 +594,20 @@ impl RouterCtl {
                 SrcBlk{id: SrcBlkId::Reserved(0xff), ch: 0xff}
             };
 
+            // Disconnection is always allowed.
+            if src.id != SrcBlkId::Reserved(0xff) {
+                if !state.as_ref().is_dst_blk_available(&dst) {
+                    let label = state.get_dst_blk_label(dst, rate_mode);
+                    let msg = format!("{} is not available in current sampling rate", label);
+                    Err(Error::new(FileError::Inval, &msg))?;
+                }
+                if !state.as_ref().is_src_blk_available(&src) {
+                    let label = state.get_src_blk_label(&src, rate_mode);
+                    let msg = format!("{} is not available in current sampling rate", label);
+                    Err(Error::new(FileError::Inval, &msg))?;
+                }
+            }
+
             match entries.iter_mut().find(|entry| entry.dst.eq(&dst)) {
                 Some(entry) => entry.src = src,
                 None => entries.push(RouterEntry{dst, src, ..Default::default()}),
@@ -786,3 +952,130 @@ impl StandaloneCtl {
         }
     }
 }
+
+#[derive(Default, Debug)]
+pub struct StreamFormatCtl {
+    // The count of PCM channels in each stream at low, middle, and high rate mode.
+    tx_pcm_counts: [Vec<i32>;3],
+    rx_pcm_counts: [Vec<i32>;3],
+}
+
+impl StreamFormatCtl {
+    const TX_PCM_CHANNELS_NAME: &'static str = "tx-stream-pcm-channels";
+    const RX_PCM_CHANNELS_NAME: &'static str = "rx-stream-pcm-channels";
+
+    const RATE_MODES: [RateMode;3] = [RateMode::Low, RateMode::Middle, RateMode::High];
+
+    const COUNT_MIN: i32 = 0;
+    const COUNT_MAX: i32 = u8::MAX as i32;
+    const COUNT_STEP: i32 = 1;
+
+    pub fn load(&mut self, node: &FwNode, proto: &FwReq, sections: &ExtensionSections,
+                caps: &ExtensionCaps, clk_caps: &ClockCaps, timeout_ms: u32, card_cntr: &mut CardCntr)
+        -> Result<(), Error>
+    {
+        let max_tx_count = caps.general.max_tx_streams as usize;
+        let max_rx_count = caps.general.max_rx_streams as usize;
+
+        let rates = clk_caps.get_rate_entries();
+        Self::RATE_MODES.iter()
+            .enumerate()
+            .filter(|(_, &m)| rates.iter().any(|&r| RateMode::from(r) == m))
+            .try_for_each(|(i, &m)| {
+                proto.read_current_stream_format_entries(node, sections, caps, m, timeout_ms)
+                    .map(|(tx, rx)| {
+                        self.tx_pcm_counts[i] = tx.iter().map(|entry| entry.pcm_count as i32).collect();
+                        self.rx_pcm_counts[i] = rx.iter().map(|entry| entry.pcm_count as i32).collect();
+                    })
+            })?;
+
+        // The stream unavailable in the rate mode has no channel.
+        self.tx_pcm_counts.iter_mut().for_each(|counts| counts.resize(max_tx_count, 0));
+        self.rx_pcm_counts.iter_mut().for_each(|counts| counts.resize(max_rx_count, 0));
+
+        if max_tx_count > 0 {
+            let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::TX_PCM_CHANNELS_NAME, 0);
+            let _ = card_cntr.add_int_elems(&elem_id, Self::RATE_MODES.len(),
+                                            Self::COUNT_MIN, Self::COUNT_MAX, Self::COUNT_STEP,
+                                            max_tx_count, None, false)?;
+        }
+
+        if max_rx_count > 0 {
+            let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::RX_PCM_CHANNELS_NAME, 0);
+            let _ = card_cntr.add_int_elems(&elem_id, Self::RATE_MODES.len(),
+                                            Self::COUNT_MIN, Self::COUNT_MAX, Self::COUNT_STEP,
+                                            max_rx_count, None, false)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn read(&self, elem_id: &ElemId, elem_value: &mut ElemValue) -> Result<bool, Error> {
+        match elem_id.get_name().as_str() {
+            Self::TX_PCM_CHANNELS_NAME => {
+                let index = elem_id.get_index() as usize;
+                elem_value.set_int(&self.tx_pcm_counts[index]);
+                Ok(true)
+            }
+            Self::RX_PCM_CHANNELS_NAME => {
+                let index = elem_id.get_index() as usize;
+                elem_value.set_int(&self.rx_pcm_counts[index]);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rms_window_roll_over() {
+        let mut ctl = MeterCtl::default();
+
+        (0..MeterCtl::RMS_WINDOW_SIZE).for_each(|_| {
+            assert_eq!(ctl.push_peaks(vec![0, 30]), vec![0, 30]);
+        });
+        assert_eq!(ctl.peak_history.len(), MeterCtl::RMS_WINDOW_SIZE);
+
+        // The oldest peaks are dropped from the window.
+        let rms = ctl.push_peaks(vec![60, 30]);
+        assert_eq!(ctl.peak_history.len(), MeterCtl::RMS_WINDOW_SIZE);
+        assert_eq!(ctl.peak_history.front(), Some(&vec![0, 30]));
+        assert_eq!(ctl.peak_history.back(), Some(&vec![60, 30]));
+        let expected = (60f64.powi(2) / MeterCtl::RMS_WINDOW_SIZE as f64).sqrt().round() as i32;
+        assert_eq!(rms, vec![expected, 30]);
+
+        (1..MeterCtl::RMS_WINDOW_SIZE).for_each(|_| {
+            let _ = ctl.push_peaks(vec![60, 30]);
+        });
+        assert_eq!(ctl.peak_history.len(), MeterCtl::RMS_WINDOW_SIZE);
+        assert_eq!(ctl.push_peaks(vec![60, 30]), vec![60, 30]);
+    }
+
+    #[test]
+    fn test_rms_history_cleared_by_mode() {
+        let mut ctl = MeterCtl::default();
+        (0..3).for_each(|_| {
+            let _ = ctl.push_peaks(vec![10, 20]);
+        });
+
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, MeterCtl::METER_MODE_NAME, 0);
+        let elem_value = ElemValue::new();
+
+        elem_value.set_enum(&[1]);
+        assert!(ctl.write(&elem_id, &elem_value).unwrap());
+        assert_eq!(ctl.mode, MeterMode::Rms);
+        assert!(ctl.peak_history.is_empty());
+
+        // The RMS is computed from the peaks after the switch.
+        assert_eq!(ctl.push_peaks(vec![40, 0]), vec![40, 0]);
+
+        elem_value.set_enum(&[0]);
+        assert!(ctl.write(&elem_id, &elem_value).unwrap());
+        assert_eq!(ctl.mode, MeterMode::Peak);
+        assert!(ctl.peak_history.is_empty());
+    }
+}
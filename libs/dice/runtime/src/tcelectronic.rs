@@ -14,3 +14,218 @@ pub mod midi_send_ctl;
 pub mod standalone_ctl;
 pub mod prog_ctl;
 pub mod shell_ctl;
+#[cfg(feature = "tc-segment-debug")]
+pub mod segment_debug_ctl;
+pub mod refresh_ctl;
+
+use glib::Error;
+
+use alsactl::{ElemId, ElemValue};
+
+use hinawa::{FwNode, SndDice};
+
+use core::card_cntr::*;
+
+use dice_protocols::tcat::*;
+use dice_protocols::tcelectronic::*;
+use dice_protocols::tcelectronic::shell::{k8::*, k24d::*, klive::*, itwin::*};
+use dice_protocols::tcelectronic::{studio::*, desktop::*};
+
+#[cfg(feature = "tc-segment-debug")]
+use self::segment_debug_ctl::*;
+use self::refresh_ctl::*;
+
+/// The trait to represent the set of segments in model.
+pub trait TcKonnektSegments {
+    /// Retrieve flags of notification for all of segments to which the model parses notification.
+    fn get_notified_flags(&self) -> u32;
+
+    /// Retrieve the name, offset, and size of all segments, to dump raw content of registers.
+    fn get_dump_ranges(&self) -> Vec<(&'static str, usize, usize)>;
+}
+
+fn segment_range<U>(name: &'static str, _: &TcKonnektSegment<U>) -> (&'static str, usize, usize)
+    where U: TcKonnektSegmentData,
+          TcKonnektSegment<U>: TcKonnektSegmentSpec,
+{
+    let offset = TC_KONNEKT_SEGMENT_BASE_OFFSET + TcKonnektSegment::<U>::OFFSET;
+    (name, offset, TcKonnektSegment::<U>::SIZE)
+}
+
+impl TcKonnektSegments for K8Segments {
+    fn get_notified_flags(&self) -> u32 {
+        self.hw_state.get_flag() | self.mixer_state.get_flag() | self.config.get_flag() |
+        self.knob.get_flag()
+    }
+
+    fn get_dump_ranges(&self) -> Vec<(&'static str, usize, usize)> {
+        vec![
+            segment_range("knob", &self.knob),
+            segment_range("config", &self.config),
+            segment_range("mixer-state", &self.mixer_state),
+            segment_range("mixer-meter", &self.mixer_meter),
+            segment_range("hw-state", &self.hw_state),
+        ]
+    }
+}
+
+// Konnekt 24d, Konnekt Live, and Impact Twin have the same layout of segments.
+macro_rules! impl_shell_segments_with_effects {
+    ($segments:ty) => {
+        impl TcKonnektSegments for $segments {
+            fn get_notified_flags(&self) -> u32 {
+                self.ch_strip_state.get_flag() | self.reverb_state.get_flag() |
+                self.hw_state.get_flag() | self.mixer_state.get_flag() | self.config.get_flag() |
+                self.knob.get_flag()
+            }
+
+            fn get_dump_ranges(&self) -> Vec<(&'static str, usize, usize)> {
+                vec![
+                    segment_range("knob", &self.knob),
+                    segment_range("config", &self.config),
+                    segment_range("mixer-state", &self.mixer_state),
+                    segment_range("reverb-state", &self.reverb_state),
+                    segment_range("ch-strip-state", &self.ch_strip_state),
+                    segment_range("mixer-meter", &self.mixer_meter),
+                    segment_range("hw-state", &self.hw_state),
+                    segment_range("reverb-meter", &self.reverb_meter),
+                    segment_range("ch-strip-meter", &self.ch_strip_meter),
+                ]
+            }
+        }
+    };
+}
+
+impl_shell_segments_with_effects!(K24dSegments);
+impl_shell_segments_with_effects!(KliveSegments);
+impl_shell_segments_with_effects!(ItwinSegments);
+
+impl TcKonnektSegments for StudioSegments {
+    fn get_notified_flags(&self) -> u32 {
+        self.ch_strip_state.get_flag() | self.reverb_state.get_flag() | self.hw_state.get_flag() |
+        self.phys_out.get_flag() | self.mixer_state.get_flag() | self.remote.get_flag()
+    }
+
+    fn get_dump_ranges(&self) -> Vec<(&'static str, usize, usize)> {
+        vec![
+            segment_range("out-level", &self.out_level),
+            segment_range("remote", &self.remote),
+            segment_range("config", &self.config),
+            segment_range("mixer-state", &self.mixer_state),
+            segment_range("phys-out", &self.phys_out),
+            segment_range("reverb-state", &self.reverb_state),
+            segment_range("ch-strip-state", &self.ch_strip_state),
+            segment_range("hw-state", &self.hw_state),
+            segment_range("mixer-meter", &self.mixer_meter),
+            segment_range("reverb-meter", &self.reverb_meter),
+            segment_range("ch-strip-meter", &self.ch_strip_meter),
+        ]
+    }
+}
+
+impl TcKonnektSegments for DesktopSegments {
+    fn get_notified_flags(&self) -> u32 {
+        self.panel.get_flag() | self.hw_state.get_flag()
+    }
+
+    fn get_dump_ranges(&self) -> Vec<(&'static str, usize, usize)> {
+        vec![
+            segment_range("hw-state", &self.hw_state),
+            segment_range("config", &self.config),
+            segment_range("mixer", &self.mixer),
+            segment_range("panel", &self.panel),
+            segment_range("meter", &self.meter),
+        ]
+    }
+}
+
+/// The structure to represent controls for the set of segments, shared by all of models.
+#[derive(Default, Debug)]
+pub struct TcKonnektSegmentsCtl {
+    pub notified_elem_list: Vec<ElemId>,
+    pub measured_elem_list: Vec<ElemId>,
+    refresh_ctl: RefreshCtl,
+    #[cfg(feature = "tc-segment-debug")]
+    segment_debug_ctl: SegmentDebugCtl,
+}
+
+impl TcKonnektSegmentsCtl {
+    pub fn load(&mut self, card_cntr: &mut CardCntr) -> Result<(), Error> {
+        #[cfg(feature = "tc-segment-debug")]
+        {
+            self.segment_debug_ctl.load(card_cntr)?;
+            self.measured_elem_list.extend_from_slice(&self.segment_debug_ctl.measured_elem_list);
+        }
+
+        self.refresh_ctl.load(card_cntr)?;
+        self.notified_elem_list.extend_from_slice(&self.refresh_ctl.notified_elem_list);
+
+        Ok(())
+    }
+
+    pub fn read(&self, elem_id: &ElemId, elem_value: &mut ElemValue) -> Result<bool, Error> {
+        self.refresh_ctl.read(elem_id, elem_value)
+    }
+
+    #[cfg_attr(not(feature = "tc-segment-debug"), allow(unused_variables))]
+    pub fn measure_states<T>(&mut self, unit: &SndDice, proto: &T, timeout_ms: u32)
+        -> Result<(), Error>
+        where T: GeneralProtocol<FwNode>,
+    {
+        #[cfg(feature = "tc-segment-debug")]
+        self.segment_debug_ctl.measure_states(unit, proto, timeout_ms)?;
+        Ok(())
+    }
+
+    #[cfg_attr(not(feature = "tc-segment-debug"), allow(unused_variables))]
+    pub fn measure_elem(&self, elem_id: &ElemId, elem_value: &mut ElemValue)
+        -> Result<bool, Error>
+    {
+        #[cfg(feature = "tc-segment-debug")]
+        if self.segment_debug_ctl.measure_elem(elem_id, elem_value)? {
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+/// The trait for operations shared by models, for the set of segments.
+pub trait TcKonnektModel : NotifyModel<SndDice, u32> {
+    type Segments: TcKonnektSegments;
+
+    fn segments(&self) -> &Self::Segments;
+    fn segments_ctl(&mut self) -> &mut TcKonnektSegmentsCtl;
+
+    /// Retrieve the list of segments, to dump raw content of registers.
+    fn get_dump_ranges(&self) -> Vec<(&'static str, usize, usize)> {
+        self.segments().get_dump_ranges()
+    }
+
+    /// Read all of notified segments again when the element for refresh is written, by parsing the
+    /// message which consists of the flags of the segments. The segments without notification are
+    /// not read, since their content is changed just by applications. The flags of general
+    /// notification defined by TCAT are not included, so that the state of sampling clock is not
+    /// parsed. Return false if the element is not the target.
+    fn write_segments_ctl(&mut self, unit: &mut SndDice, elem_id: &ElemId, new: &ElemValue)
+        -> Result<bool, Error>
+    {
+        match self.segments_ctl().refresh_ctl.write(elem_id, new)? {
+            Some(requested) => {
+                if requested {
+                    let msg = self.segments().get_notified_flags();
+                    self.parse_notification(unit, &msg)?;
+                }
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Retrieve all of notified elements when the segments were read again by the last write
+    /// operation.
+    fn take_refreshed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        if self.segments_ctl().refresh_ctl.take_refreshed() {
+            self.get_notified_elem_list(elem_id_list);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2020 Takashi Sakamoto
 
-use glib::Error;
+use glib::{Error, FileError};
 
 use alsactl::{ElemId, ElemIfaceType, ElemValue};
 
@@ -73,6 +73,9 @@ fn create_ch_strip_src_type_labels() -> Vec<String> {
 pub struct ChStripCtl {
     pub measured_elem_list: Vec<ElemId>,
     pub notified_elem_list: Vec<ElemId>,
+    // The parameters of deesser and limitter are not available in RIAA modes of Konnekt Live.
+    riaa_mode: bool,
+    changed_elem_list: Vec<ElemId>,
 }
 
 impl ChStripCtl {
@@ -81,6 +84,8 @@ impl ChStripCtl {
     const EQ_BYPASS_NAME: &'static str = "equalizer-bypass";
     const LIMITTER_BYPASS_NAME: &'static str = "limitter-bypass";
     const BYPASS_NAME: &'static str = "ch-strip-bypass";
+    const COPY_NAME: &'static str = "ch-strip-copy";
+    const RESET_NAME: &'static str = "ch-strip-reset";
 
     const COMP_INPUT_GAIN_NAME: &'static str = "comp-input-gain";
     const COMP_MAKE_UP_GAIN: &'static str = "comp-make-up-gain";
@@ -154,6 +159,8 @@ impl ChStripCtl {
     const GAIN_METER_STEP: i32 = 1;
     const GAIN_METER_TLV: DbInterval = DbInterval{min: -2400, max: 1800, linear: false, mute_avail: false};
 
+    const COPY_NONE_LABEL: &'static str = "None";
+
     pub fn load<T, S, M>(&mut self, unit: &SndDice, proto: &T, state_segment: &mut TcKonnektSegment<S>,
                          meter_segment: &mut TcKonnektSegment<M>, timeout_ms: u32, card_cntr: &mut CardCntr)
         -> Result<(), Error>
@@ -177,6 +184,20 @@ impl ChStripCtl {
         self.state_add_bool_elem(card_cntr, &states, Self::LIMITTER_BYPASS_NAME, 1, true)?;
         self.state_add_bool_elem(card_cntr, &states, Self::BYPASS_NAME, 1, true)?;
 
+        if states.len() > 1 {
+            let mut labels = Self::compute_copy_pairs(states.len()).iter()
+                .map(|(src, dst)| format!("{}-to-{}", src + 1, dst + 1))
+                .collect::<Vec<_>>();
+            labels.insert(0, Self::COPY_NONE_LABEL.to_string());
+            let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::COPY_NAME, 0);
+            card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)
+                .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
+        }
+
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::RESET_NAME, 0);
+        card_cntr.add_bool_elems(&elem_id, 1, 1, true)
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
+
         // Controls for compressor part.
         self.state_add_int_elem(card_cntr, &states, Self::COMP_INPUT_GAIN_NAME, 1,
                                 Self::COMP_GAIN_MIN, Self::COMP_GAIN_MAX, Self::COMP_GAIN_STEP,
@@ -235,6 +256,17 @@ impl ChStripCtl {
         Ok(())
     }
 
+    // The pairs of source and destination to copy parameters.
+    fn compute_copy_pairs(count: usize) -> Vec<(usize, usize)> {
+        (0..count)
+            .flat_map(|src| {
+                (0..count)
+                    .filter(move |&dst| dst != src)
+                    .map(move |dst| (src, dst))
+            })
+            .collect()
+    }
+
     fn state_add_int_elem(&mut self, card_cntr: &mut CardCntr, states: &[ChStripState], name: &str,
                           count: usize, min: i32, max: i32, step: i32, tlv: Option<&[u32]>, unlock: bool)
         -> Result<(), Error>
@@ -318,6 +350,28 @@ impl ChStripCtl {
         .map(|_| true)
     }
 
+    /// Cache whether the channel strip effect runs in RIAA mode, in which the parameters of deesser
+    /// and limitter are not available.
+    pub fn cache_riaa_mode(&mut self, riaa_mode: bool) {
+        self.riaa_mode = riaa_mode;
+    }
+
+    fn check_fabrik_c_param(&self, name: &str) -> Result<(), Error> {
+        let fabrik_c_only = [
+            Self::DEESSER_BYPASS_NAME,
+            Self::DEESSER_RATIO_NAME,
+            Self::LIMITTER_BYPASS_NAME,
+            Self::LIMITTER_THRESHOLD,
+        ].iter()
+            .any(|&n| n == name);
+        if self.riaa_mode && fabrik_c_only {
+            let msg = format!("The parameter {} is not available in RIAA mode", name);
+            Err(Error::new(FileError::Inval, &msg))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn write<T, S>(&mut self, unit: &SndDice, proto: &T, segment: &mut TcKonnektSegment<S>,
                        elem_id: &ElemId, old: &ElemValue, new: &ElemValue, timeout_ms: u32)
         -> Result<bool, Error>
@@ -325,6 +379,8 @@ impl ChStripCtl {
               S: TcKonnektSegmentData + AsMut<[ChStripState]>,
               TcKonnektSegment<S>: TcKonnektSegmentSpec,
     {
+        self.check_fabrik_c_param(elem_id.get_name().as_str())?;
+
         match elem_id.get_name().as_str() {
             Self::SRC_TYPE_NAME => {
                 self.state_write_elem(unit, proto, segment, old, new, timeout_ms,
@@ -396,10 +452,68 @@ impl ChStripCtl {
                 self.state_write_elem(unit, proto, segment, old, new, timeout_ms,
                                       |state, val: i32| state.limitter.threshold = val as u32)
             }
+            Self::COPY_NAME => {
+                let riaa_mode = self.riaa_mode;
+                ElemValueAccessor::<u32>::get_val(new, |val| {
+                    // Nothing to copy.
+                    if val == 0 {
+                        return Ok(());
+                    }
+
+                    let states = segment.data.as_mut();
+                    Self::compute_copy_pairs(states.len()).iter()
+                        .nth(val as usize - 1)
+                        .ok_or_else(|| {
+                            let msg = format!("Invalid value for index of pair of channel strips: {}", val);
+                            Error::new(FileError::Inval, &msg)
+                        })
+                        .map(|&(src, dst)| {
+                            let mut state = states[src].clone();
+                            // Keep the parameters unavailable in RIAA mode.
+                            if riaa_mode {
+                                state.deesser = states[dst].deesser.clone();
+                                state.limitter_bypass = states[dst].limitter_bypass;
+                                state.limitter = states[dst].limitter.clone();
+                            }
+                            states[dst] = state;
+                        })?;
+
+                    proto.write_segment(&unit.get_node(), segment, timeout_ms)
+                })?;
+                // The operation is one-shot, and the parameters of destination are changed.
+                self.changed_elem_list.extend_from_slice(&self.notified_elem_list);
+                Ok(true)
+            }
+            Self::RESET_NAME => {
+                ElemValueAccessor::<bool>::get_val(new, |val| {
+                    if !val {
+                        return Ok(());
+                    }
+
+                    // NOTE: no command is known to reset the state of DSP. Bypass whole parts
+                    // once, then restore current parameters. The whole segment is written so that
+                    // the device receives the transactions even if the parts are already bypassed.
+                    let states = segment.data.as_mut().to_vec();
+                    segment.data.as_mut().iter_mut()
+                        .for_each(|state| state.bypass = true);
+                    let node = unit.get_node();
+                    let res = proto.write_whole_segment(&node, segment, timeout_ms);
+                    segment.data.as_mut().clone_from_slice(&states);
+                    res.and_then(|_| proto.write_whole_segment(&node, segment, timeout_ms))
+                })?;
+                // The operation is one-shot.
+                self.changed_elem_list.push(elem_id.clone());
+                Ok(true)
+            }
             _ => Ok(false),
         }
     }
 
+    /// Retrieve the list of elements of which value is changed by the last write operation.
+    pub fn take_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        elem_id_list.append(&mut self.changed_elem_list);
+    }
+
     fn state_write_elem<T, S, U, F>(&mut self, unit: &SndDice, proto: &T, segment: &mut TcKonnektSegment<S>,
                                     old: &ElemValue, new: &ElemValue, timeout_ms: u32, cb: F)
         -> Result<bool, Error>
@@ -480,6 +594,16 @@ impl ChStripCtl {
             Self::LIMITTER_THRESHOLD => {
                 self.state_read_elem(segment, elem_value, |state| state.limitter.threshold as i32)
             }
+            Self::COPY_NAME => {
+                // The operation is one-shot.
+                ElemValueAccessor::<u32>::set_val(elem_value, || Ok(0))
+                .map(|_| true)
+            }
+            Self::RESET_NAME => {
+                // The operation is one-shot.
+                ElemValueAccessor::<bool>::set_val(elem_value, || Ok(false))
+                .map(|_| true)
+            }
             _ => Ok(false),
         }
     }
@@ -2,7 +2,7 @@
 // Copyright (c) 2020 Takashi Sakamoto
 use glib::{Error, FileError};
 
-use alsactl::{ElemId, ElemIfaceType, ElemValue, ElemValueExt};
+use alsactl::{ElemId, ElemIfaceType, ElemValue};
 
 use hinawa::FwReq;
 use hinawa::{SndDice, SndUnitExt};
@@ -10,13 +10,15 @@ use hinawa::{SndDice, SndUnitExt};
 use core::card_cntr::*;
 use core::elem_value_accessor::*;
 
-use alsa_ctl_tlv_codec::items::DbInterval;
+use alsa_ctl_tlv_codec::{items::DbInterval, range_utils::ValueRange};
 
 use dice_protocols::tcat::{*, global_section::*};
 use dice_protocols::tcelectronic::{*, desktop::*};
 
 use crate::common_ctl::*;
+use crate::meter_scale::*;
 use super::{fw_led_ctl::*, standalone_ctl::*};
+use super::{TcKonnektSegmentsCtl, TcKonnektModel};
 
 #[derive(Default)]
 pub struct Desktopk6Model{
@@ -29,10 +31,23 @@ pub struct Desktopk6Model{
     mixer_ctl: MixerCtl,
     standalone_ctl: TcKonnektStandaloneCtl,
     hw_state_ctl: HwStateCtl,
+    segments_ctl: TcKonnektSegmentsCtl,
 }
 
 const TIMEOUT_MS: u32 = 20;
 
+impl TcKonnektModel for Desktopk6Model {
+    type Segments = DesktopSegments;
+
+    fn segments(&self) -> &DesktopSegments {
+        &self.segments
+    }
+
+    fn segments_ctl(&mut self) -> &mut TcKonnektSegmentsCtl {
+        &mut self.segments_ctl
+    }
+}
+
 impl CtlModel<SndDice> for Desktopk6Model {
     fn load(&mut self, unit: &mut SndDice, card_cntr: &mut CardCntr) -> Result<(), Error> {
         let node = unit.get_node();
@@ -53,6 +68,7 @@ impl CtlModel<SndDice> for Desktopk6Model {
         self.mixer_ctl.load(card_cntr)?;
         self.standalone_ctl.load(card_cntr)?;
         self.hw_state_ctl.load(card_cntr)?;
+        self.segments_ctl.load(card_cntr)?;
 
         Ok(())
     }
@@ -72,6 +88,8 @@ impl CtlModel<SndDice> for Desktopk6Model {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -93,10 +111,17 @@ impl CtlModel<SndDice> for Desktopk6Model {
         } else if self.hw_state_ctl.write(unit, &self.proto, &mut self.segments, elem_id, new,
                                           TIMEOUT_MS)? {
             Ok(true)
+        } else if self.write_segments_ctl(unit, elem_id, new)? {
+            Ok(true)
         } else {
             Ok(false)
         }
     }
+
+    fn get_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        self.panel_ctl.take_changed_elem_list(elem_id_list);
+        self.take_refreshed_elem_list(elem_id_list);
+    }
 }
 
 impl NotifyModel<SndDice, u32> for Desktopk6Model {
@@ -104,6 +129,7 @@ impl NotifyModel<SndDice, u32> for Desktopk6Model {
         elem_id_list.extend_from_slice(&self.ctl.notified_elem_list);
         elem_id_list.extend_from_slice(&self.panel_ctl.notified_elem_list);
         elem_id_list.extend_from_slice(&self.hw_state_ctl.0);
+        elem_id_list.extend_from_slice(&self.segments_ctl.notified_elem_list);
     }
 
     fn parse_notification(&mut self, unit: &mut SndDice, msg: &u32) -> Result<(), Error> {
@@ -124,6 +150,8 @@ impl NotifyModel<SndDice, u32> for Desktopk6Model {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -134,12 +162,14 @@ impl MeasureModel<hinawa::SndDice> for Desktopk6Model {
     fn get_measure_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
         elem_id_list.extend_from_slice(&self.ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.meter_ctl.0);
+        elem_id_list.extend_from_slice(&self.segments_ctl.measured_elem_list);
     }
 
     fn measure_states(&mut self, unit: &mut SndDice) -> Result<(), Error> {
         self.ctl.measure_states(unit, &self.proto, &self.sections, TIMEOUT_MS)?;
 
         self.proto.read_segment(&unit.get_node(), &mut self.segments.meter, TIMEOUT_MS)?;
+        self.segments_ctl.measure_states(unit, &self.proto, TIMEOUT_MS)?;
 
         Ok(())
     }
@@ -151,6 +181,8 @@ impl MeasureModel<hinawa::SndDice> for Desktopk6Model {
             Ok(true)
         } else if self.meter_ctl.read(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.measure_elem(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -167,6 +199,14 @@ impl AsRef<FwReq> for Desktopk6Proto {
     }
 }
 
+fn compute_db_interval(min: i32, max: i32, step: i32, min_db: i32, max_db: i32)
+    -> Result<Vec<u32>, Error>
+{
+    DbInterval::from_valuerange(&ValueRange{min, max, step}, min_db, max_db)
+        .map(|interval| interval.into())
+        .map_err(|e| Error::new(FileError::Inval, &e.to_string()))
+}
+
 #[derive(Default, Debug)]
 pub struct MeterCtl(Vec<ElemId>);
 
@@ -175,10 +215,12 @@ impl MeterCtl {
     const MIXER_OUT_NAME: &'static str = "mixer-output-meters";
     const STREAM_IN_NAME: &'static str = "stream-input-meters";
 
-    const METER_MIN: i32 = -1000;
-    const METER_MAX: i32 = 0;
-    const METER_STEP: i32 = 1;
-    const METER_TLV: DbInterval = DbInterval{min: -9400, max: 0, linear: false, mute_avail: false};
+    const METER_SCALE: MeterScale = MeterScale{
+        min: -1000,
+        max: 0,
+        step: 1,
+        tlv: DbInterval{min: -9400, max: 0, linear: false, mute_avail: false},
+    };
 
     fn load(&mut self, segments: &DesktopSegments, card_cntr: &mut CardCntr) -> Result<(), Error> {
         let labels = (0..segments.meter.data.analog_inputs.len())
@@ -203,8 +245,7 @@ impl MeterCtl {
         -> Result<(), Error>
     {
         let elem_id = alsactl::ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, name, 0);
-        card_cntr.add_int_elems(&elem_id, 1, Self::METER_MIN, Self::METER_MAX, Self::METER_STEP,
-                                labels.len(), Some(&Into::<Vec<u32>>::into(Self::METER_TLV)), false)
+        Self::METER_SCALE.add_int_elems(card_cntr, &elem_id, 1, labels.len())
             .map(|mut elem_id_list| self.0.append(&mut elem_id_list))
     }
 
@@ -213,15 +254,15 @@ impl MeterCtl {
     {
         match elem_id.get_name().as_str() {
             Self::ANALOG_IN_NAME => {
-                elem_value.set_int(&segments.meter.data.analog_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &segments.meter.data.analog_inputs);
                 Ok(true)
             }
             Self::MIXER_OUT_NAME => {
-                elem_value.set_int(&segments.meter.data.mixer_outputs);
+                Self::METER_SCALE.set_vals(elem_value, &segments.meter.data.mixer_outputs);
                 Ok(true)
             }
             Self::STREAM_IN_NAME => {
-                elem_value.set_int(&segments.meter.data.stream_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &segments.meter.data.stream_inputs);
                 Ok(true)
             }
             _ => Ok(false),
@@ -281,7 +322,7 @@ impl PanelCtl {
             .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
 
         self.fw_led_ctl.load(card_cntr)?;
-        self.notified_elem_list.extend_from_slice(&self.fw_led_ctl.0);
+        self.notified_elem_list.extend_from_slice(&self.fw_led_ctl.notified_elem_list);
 
         Ok(())
     }
@@ -345,6 +386,10 @@ impl PanelCtl {
             _ => self.fw_led_ctl.write(unit, proto, &mut segments.panel, elem_id, elem_value, timeout_ms),
         }
     }
+
+    fn take_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        self.fw_led_ctl.take_changed_elem_list(elem_id_list);
+    }
 }
 
 fn hp_src_to_string(src: DesktopHpSrc) -> String {
@@ -375,7 +420,8 @@ impl MixerCtl {
     const LEVEL_MIN: i32 = -1000;
     const LEVEL_MAX: i32 = 0;
     const LEVEL_STEP: i32 = 1;
-    const LEVEL_TLV: DbInterval = DbInterval{min: -9400, max: 0, linear: false, mute_avail: false};
+    const LEVEL_DB_MIN: i32 = -9400;
+    const LEVEL_DB_MAX: i32 = 0;
 
     const BALANCE_MIN: i32 = -50;
     const BALANCE_MAX: i32 = 50;
@@ -384,35 +430,38 @@ impl MixerCtl {
     const HP_SRCS: [DesktopHpSrc;2] = [DesktopHpSrc::Stream23, DesktopHpSrc::Mixer01];
 
     fn load(&mut self, card_cntr: &mut CardCntr) -> Result<(), Error> {
+        let tlv = compute_db_interval(Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
+                                      Self::LEVEL_DB_MIN, Self::LEVEL_DB_MAX)?;
+
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_MIC_INST_SRC_LEVEL_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                        2, Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)), true)?;
+                                        2, Some(&tlv), true)?;
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_MIC_INST_SRC_BALANCE_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::BALANCE_MIN, Self::BALANCE_MAX, Self::BALANCE_STEP,
                                         2, None, true)?;
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_MIC_INST_SRC_SEND_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                        2, Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)), true)?;
+                                        2, Some(&tlv), true)?;
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_DUAL_INST_SRC_LEVEL_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                        2, Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)), true)?;
+                                        2, Some(&tlv), true)?;
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_DUAL_INST_SRC_BALANCE_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::BALANCE_MIN, Self::BALANCE_MAX, Self::BALANCE_STEP,
                                         2, None, true)?;
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_DUAL_INST_SRC_SEND_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                        2, Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)), true)?;
+                                        2, Some(&tlv), true)?;
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_STEREO_IN_SRC_LEVEL_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                        1, Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)), true)?;
+                                        1, Some(&tlv), true)?;
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_STEREO_IN_SRC_BALANCE_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::BALANCE_MIN, Self::BALANCE_MAX, Self::BALANCE_STEP,
                                         1, None, true)?;
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_STEREO_IN_SRC_SEND_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                        1, Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)), true)?;
+                                        1, Some(&tlv), true)?;
 
         let labels: Vec<String> = Self::HP_SRCS.iter()
             .map(|&s| hp_src_to_string(s))
@@ -638,7 +687,8 @@ impl HwStateCtl {
     const DIM_LEVEL_MIN: i32 = -1000;
     const DIM_LEVEL_MAX: i32 = -60;
     const DIM_LEVEL_STEP: i32 = 1;
-    const DIM_LEVEL_TLV: DbInterval = DbInterval{min: -9400, max: -600, linear: false, mute_avail: false};
+    const DIM_LEVEL_DB_MIN: i32 = -9400;
+    const DIM_LEVEL_DB_MAX: i32 = -600;
 
     fn load(&mut self, card_cntr: &mut CardCntr)
         -> Result<(), Error>
@@ -661,9 +711,11 @@ impl HwStateCtl {
         card_cntr.add_bool_elems(&elem_id, 1, 1, true)
             .map(|mut elem_id_list| self.0.append(&mut elem_id_list))?;
 
+        let tlv = compute_db_interval(Self::DIM_LEVEL_MIN, Self::DIM_LEVEL_MAX, Self::DIM_LEVEL_STEP,
+                                      Self::DIM_LEVEL_DB_MIN, Self::DIM_LEVEL_DB_MAX)?;
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_OUTPUT_DIM_LEVEL_NAME, 0);
         let _ = card_cntr.add_int_elems(&elem_id, 1, Self::DIM_LEVEL_MIN, Self::DIM_LEVEL_MAX, Self::DIM_LEVEL_STEP,
-                                        1, Some(&Into::<Vec<u32>>::into(Self::DIM_LEVEL_TLV)), true)?;
+                                        1, Some(&tlv), true)?;
 
         let labels: Vec<String> = Self::INPUT_SCENES.iter()
             .map(|l| input_scene_to_string(l))
@@ -23,10 +23,16 @@ pub fn firewire_led_state_to_string(state: &FireWireLedState) -> String {
 }
 
 #[derive(Default, Debug)]
-pub struct FwLedCtl(pub Vec<ElemId>);
+pub struct FwLedCtl {
+    pub notified_elem_list: Vec<ElemId>,
+    // Whether to invert the interpretation of on and off.
+    inverted: bool,
+    changed_elem_list: Vec<ElemId>,
+}
 
 impl FwLedCtl {
     const STATE_NAME: &'static str = "FireWire-LED-state";
+    const INVERT_NAME: &'static str = "FireWire-LED-invert";
 
     const STATES: [FireWireLedState;4] = [
         FireWireLedState::Off,
@@ -41,11 +47,24 @@ impl FwLedCtl {
             .collect::<Vec<_>>();
         let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::STATE_NAME, 0);
         card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)
-            .map(|mut elem_id_list| self.0.append(&mut elem_id_list))?;
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
+
+        // NOTE: just for presentation of the above element, without any change of hardware.
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::INVERT_NAME, 0);
+        let _ = card_cntr.add_bool_elems(&elem_id, 1, 1, true)?;
 
         Ok(())
     }
 
+    // The operation is symmetric for both directions.
+    fn convert(state: FireWireLedState, invert: bool) -> FireWireLedState {
+        match state {
+            FireWireLedState::Off if invert => FireWireLedState::On,
+            FireWireLedState::On if invert => FireWireLedState::Off,
+            _ => state,
+        }
+    }
+
     pub fn read<S>(&mut self, segment: &TcKonnektSegment<S>, elem_id: &ElemId, elem_value: &mut ElemValue)
         -> Result<bool, Error>
         where S: TcKonnektSegmentData + AsRef<FireWireLedState>,
@@ -53,13 +72,18 @@ impl FwLedCtl {
         match elem_id.get_name().as_str() {
             Self::STATE_NAME => {
                 ElemValueAccessor::<u32>::set_val(elem_value, || {
+                    let state = Self::convert(*segment.data.as_ref(), self.inverted);
                     let pos = Self::STATES.iter()
-                        .position(|s| s.eq(segment.data.as_ref()))
+                        .position(|s| s.eq(&state))
                         .expect("Programming error");
                     Ok(pos as u32)
                 })
                 .map(|_| true)
             }
+            Self::INVERT_NAME => {
+                ElemValueAccessor::<bool>::set_val(elem_value, || Ok(self.inverted))
+                    .map(|_| true)
+            }
             _ => Ok(false),
         }
     }
@@ -80,12 +104,44 @@ impl FwLedCtl {
                             let msg = format!("Invalid value for index of FireWire LED: {}", val);
                             Error::new(FileError::Inval, &msg)
                         })
-                        .map(|&s| *segment.data.as_mut() = s)
+                        .map(|&s| *segment.data.as_mut() = Self::convert(s, self.inverted))
                 })
                 .and_then(|_| proto.write_segment(&unit.get_node(), segment, timeout_ms))
                 .map(|_| true)
             }
+            Self::INVERT_NAME => {
+                ElemValueAccessor::<bool>::get_val(elem_value, |val| {
+                    self.inverted = val;
+                    Ok(())
+                })
+                .map(|_| {
+                    // The presentation of state is changed.
+                    self.changed_elem_list.extend_from_slice(&self.notified_elem_list);
+                    true
+                })
+            }
             _ => Ok(false),
         }
     }
+
+    pub fn take_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        elem_id_list.append(&mut self.changed_elem_list);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_led_state_convert() {
+        FwLedCtl::STATES.iter()
+            .for_each(|&state| {
+                assert_eq!(FwLedCtl::convert(state, false), state);
+                assert_eq!(FwLedCtl::convert(FwLedCtl::convert(state, true), true), state);
+            });
+        assert_eq!(FwLedCtl::convert(FireWireLedState::On, true), FireWireLedState::Off);
+        assert_eq!(FwLedCtl::convert(FireWireLedState::Off, true), FireWireLedState::On);
+        assert_eq!(FwLedCtl::convert(FireWireLedState::BlinkFast, true), FireWireLedState::BlinkFast);
+    }
 }
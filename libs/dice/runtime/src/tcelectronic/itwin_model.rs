@@ -18,6 +18,7 @@ use crate::common_ctl::*;
 use super::ch_strip_ctl::*;
 use super::reverb_ctl::*;
 use super::shell_ctl::*;
+use super::{TcKonnektSegmentsCtl, TcKonnektModel};
 
 #[derive(Default)]
 pub struct ItwinModel{
@@ -33,10 +34,23 @@ pub struct ItwinModel{
     standalone_ctl: ShellStandaloneCtl,
     knob_ctl: ShellKnobCtl,
     specific_ctl: ItwinSpecificCtl,
+    segments_ctl: TcKonnektSegmentsCtl,
 }
 
 const TIMEOUT_MS: u32 = 20;
 
+impl TcKonnektModel for ItwinModel {
+    type Segments = ItwinSegments;
+
+    fn segments(&self) -> &ItwinSegments {
+        &self.segments
+    }
+
+    fn segments_ctl(&mut self) -> &mut TcKonnektSegmentsCtl {
+        &mut self.segments_ctl
+    }
+}
+
 impl CtlModel<SndDice> for ItwinModel {
     fn load(&mut self, unit: &mut SndDice, card_cntr: &mut CardCntr) -> Result<(), Error> {
         let node = unit.get_node();
@@ -45,6 +59,7 @@ impl CtlModel<SndDice> for ItwinModel {
         let caps = self.proto.read_clock_caps(&node, &self.sections, TIMEOUT_MS)?;
         let src_labels = self.proto.read_clock_source_labels(&node, &self.sections, TIMEOUT_MS)?;
         self.ctl.load(card_cntr, &caps, &src_labels)?;
+        self.ctl.cache_clock_state(unit, &self.proto, &self.sections, TIMEOUT_MS)?;
 
         self.ch_strip_ctl.load(unit, &self.proto, &mut self.segments.ch_strip_state,
                                &mut self.segments.ch_strip_meter, TIMEOUT_MS, card_cntr)?;
@@ -63,6 +78,7 @@ impl CtlModel<SndDice> for ItwinModel {
         self.standalone_ctl.load(&self.segments.config, card_cntr)?;
         self.knob_ctl.load(&self.segments.knob, card_cntr)?;
         self.specific_ctl.load(card_cntr)?;
+        self.segments_ctl.load(card_cntr)?;
 
         Ok(())
     }
@@ -80,8 +96,8 @@ impl CtlModel<SndDice> for ItwinModel {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments.hw_state, elem_id, elem_value)? {
             Ok(true)
-        } else if self.mixer_ctl.read(&self.segments.mixer_state, &self.segments.mixer_meter, elem_id,
-                                      elem_value)? {
+        } else if self.mixer_ctl.read(&self.segments.mixer_state, &self.segments.mixer_meter,
+                                      self.ctl.get_curr_rate_mode(), elem_id, elem_value)? {
             Ok(true)
         } else if self.mixer_stream_src_pair_ctl.read(&self.segments.config, elem_id, elem_value)? {
             Ok(true)
@@ -91,6 +107,8 @@ impl CtlModel<SndDice> for ItwinModel {
             Ok(true)
         } else if self.specific_ctl.read(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -110,8 +128,8 @@ impl CtlModel<SndDice> for ItwinModel {
         } else if self.hw_state_ctl.write(unit, &self.proto, &mut self.segments.hw_state, elem_id,
                                           new, TIMEOUT_MS)? {
             Ok(true)
-        } else if self.mixer_ctl.write(unit, &self.proto, &mut self.segments.mixer_state, elem_id, old, new,
-                                       TIMEOUT_MS)? {
+        } else if self.mixer_ctl.write(unit, &self.proto, &mut self.segments.mixer_state,
+                                       self.ctl.get_curr_rate_mode(), elem_id, old, new, TIMEOUT_MS)? {
             Ok(true)
         } else if self.mixer_stream_src_pair_ctl.write(unit, &self.proto, &mut self.segments.config, elem_id,
                                                        new, TIMEOUT_MS)? {
@@ -125,10 +143,21 @@ impl CtlModel<SndDice> for ItwinModel {
         } else if self.specific_ctl.write(unit, &self.proto, &mut self.segments, elem_id, old, new,
                                           TIMEOUT_MS)? {
             Ok(true)
+        } else if self.write_segments_ctl(unit, elem_id, new)? {
+            Ok(true)
         } else {
             Ok(false)
         }
     }
+
+    fn get_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        self.ch_strip_ctl.take_changed_elem_list(elem_id_list);
+        self.reverb_ctl.take_changed_elem_list(elem_id_list);
+        self.hw_state_ctl.take_changed_elem_list(elem_id_list);
+        self.mixer_ctl.take_changed_elem_list(elem_id_list);
+        self.specific_ctl.take_changed_elem_list(elem_id_list);
+        self.take_refreshed_elem_list(elem_id_list);
+    }
 }
 
 impl NotifyModel<SndDice, u32> for ItwinModel {
@@ -139,7 +168,8 @@ impl NotifyModel<SndDice, u32> for ItwinModel {
         elem_id_list.extend_from_slice(&self.hw_state_ctl.notified_elem_list);
         elem_id_list.extend_from_slice(&self.mixer_ctl.notified_elem_list);
         elem_id_list.extend_from_slice(&self.knob_ctl.notified_elem_list);
-        elem_id_list.extend_from_slice(&self.specific_ctl.0);
+        elem_id_list.extend_from_slice(&self.specific_ctl.notified_elem_list);
+        elem_id_list.extend_from_slice(&self.segments_ctl.notified_elem_list);
     }
 
     fn parse_notification(&mut self, unit: &mut SndDice, msg: &u32) -> Result<(), Error> {
@@ -166,12 +196,15 @@ impl NotifyModel<SndDice, u32> for ItwinModel {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments.hw_state, elem_id, elem_value)? {
             Ok(true)
-        } else if self.mixer_ctl.read_notified_elem(&self.segments.mixer_state, elem_id, elem_value)? {
+        } else if self.mixer_ctl.read_notified_elem(&self.segments.mixer_state, self.ctl.get_curr_rate_mode(),
+                                                    elem_id, elem_value)? {
             Ok(true)
         } else if self.knob_ctl.read(&self.segments.knob, elem_id, elem_value)? {
             Ok(true)
         } else if self.specific_ctl.read_notified_elem(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -184,6 +217,7 @@ impl MeasureModel<hinawa::SndDice> for ItwinModel {
         elem_id_list.extend_from_slice(&self.ch_strip_ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.reverb_ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.mixer_ctl.measured_elem_list);
+        elem_id_list.extend_from_slice(&self.segments_ctl.measured_elem_list);
     }
 
     fn measure_states(&mut self, unit: &mut SndDice) -> Result<(), Error> {
@@ -194,6 +228,7 @@ impl MeasureModel<hinawa::SndDice> for ItwinModel {
                                        &mut self.segments.reverb_meter, TIMEOUT_MS)?;
 
         self.proto.read_segment(&unit.get_node(), &mut self.segments.mixer_meter, TIMEOUT_MS)?;
+        self.segments_ctl.measure_states(unit, &self.proto, TIMEOUT_MS)?;
         Ok(())
     }
 
@@ -208,6 +243,8 @@ impl MeasureModel<hinawa::SndDice> for ItwinModel {
             Ok(true)
         } else if self.mixer_ctl.read_measured_elem(&self.segments.mixer_meter, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.measure_elem(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -253,11 +290,16 @@ fn listening_mode_to_string(mode: &ListeningMode) -> String {
 }
 
 #[derive(Default, Debug)]
-struct ItwinSpecificCtl(Vec<ElemId>);
+struct ItwinSpecificCtl{
+    out_pair_link: bool,
+    notified_elem_list: Vec<ElemId>,
+    changed_elem_list: Vec<ElemId>,
+}
 
 impl ItwinSpecificCtl {
     const CLK_RECOVERY_NAME: &'static str = "clock-recovery";
     const OUT_SRC_NAME: &'static str = "output-source";
+    const OUT_PAIR_LINK_NAME: &'static str = "output-pair-link";
     const MIXER_ENABLE_NAME: &'static str = "mixer-enable";
     const LISTENING_MODE_NAME: &'static str = "listening-mode";
 
@@ -294,7 +336,13 @@ impl ItwinSpecificCtl {
             .map(|s| itwin_phys_out_src_to_string(s))
             .collect();
         let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::OUT_SRC_NAME, 0);
-        let _ = card_cntr.add_enum_elems(&elem_id, 1, ITWIN_PHYS_OUT_PAIR_COUNT, &labels, None, true)?;
+        card_cntr.add_enum_elems(&elem_id, 1, ITWIN_PHYS_OUT_PAIR_COUNT, &labels, None, true)
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
+
+        // NOTE: the link is not stored in the device. The source of output 3/4 follows the one of
+        // output 1/2 and vice versa while enabled.
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::OUT_PAIR_LINK_NAME, 0);
+        let _ = card_cntr.add_bool_elems(&elem_id, 1, 1, true)?;
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::MIXER_ENABLE_NAME, 0);
         let _ = card_cntr.add_bool_elems(&elem_id, 1, 1, true)?;
@@ -304,7 +352,7 @@ impl ItwinSpecificCtl {
             .collect();
         let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::LISTENING_MODE_NAME, 0);
         card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)
-            .map(|mut elem_id_list| self.0.append(&mut elem_id_list))?;
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
 
         Ok(())
     }
@@ -319,13 +367,8 @@ impl ItwinSpecificCtl {
                 })
                 .map(|_| true)
             }
-            Self::OUT_SRC_NAME => {
-                ElemValueAccessor::<u32>::set_vals(elem_value, ITWIN_PHYS_OUT_PAIR_COUNT, |idx| {
-                    let pos = Self::OUT_SRCS.iter()
-                        .position(|&s| s == segments.config.data.output_pair_src[idx])
-                        .expect("Programming error...");
-                    Ok(pos as u32)
-                })
+            Self::OUT_PAIR_LINK_NAME => {
+                ElemValueAccessor::<bool>::set_val(elem_value, || Ok(self.out_pair_link))
                 .map(|_| true)
             }
             Self::MIXER_ENABLE_NAME => {
@@ -361,6 +404,9 @@ impl ItwinSpecificCtl {
                         })
                         .and_then(|&s| {
                             segments.config.data.output_pair_src[idx] = s;
+                            if self.out_pair_link && idx < 2 {
+                                segments.config.data.output_pair_src[1 - idx] = s;
+                            }
                             count += 1;
                             Ok(())
                         })
@@ -368,10 +414,26 @@ impl ItwinSpecificCtl {
                 .and_then(|_| {
                     if count > 0 {
                         proto.write_segment(&unit.get_node(), &mut segments.config, timeout_ms)?;
+                        if self.out_pair_link {
+                            self.push_out_src_elem();
+                        }
                     }
                     Ok(true)
                 })
             }
+            Self::OUT_PAIR_LINK_NAME => {
+                ElemValueAccessor::<bool>::get_val(new, |val| {
+                    self.out_pair_link = val;
+                    let srcs = &mut segments.config.data.output_pair_src;
+                    if val && srcs[1] != srcs[0] {
+                        srcs[1] = srcs[0];
+                        proto.write_segment(&unit.get_node(), &mut segments.config, timeout_ms)?;
+                        self.push_out_src_elem();
+                    }
+                    Ok(())
+                })
+                .map(|_| true)
+            }
             Self::MIXER_ENABLE_NAME => {
                 ElemValueAccessor::<bool>::get_val(new, |val| {
                     segments.mixer_state.data.enabled = val;
@@ -398,10 +460,29 @@ impl ItwinSpecificCtl {
         }
     }
 
+    // The source of the other pair is changed as well while linked.
+    fn push_out_src_elem(&mut self) {
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::OUT_SRC_NAME, 0);
+        self.changed_elem_list.push(elem_id);
+    }
+
+    fn take_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        elem_id_list.append(&mut self.changed_elem_list);
+    }
+
     fn read_notified_elem(&mut self, segments: &ItwinSegments, elem_id: &ElemId, elem_value: &mut ElemValue)
         -> Result<bool, Error>
     {
         match elem_id.get_name().as_str() {
+            Self::OUT_SRC_NAME => {
+                ElemValueAccessor::<u32>::set_vals(elem_value, ITWIN_PHYS_OUT_PAIR_COUNT, |idx| {
+                    let pos = Self::OUT_SRCS.iter()
+                        .position(|&s| s == segments.config.data.output_pair_src[idx])
+                        .expect("Programming error...");
+                    Ok(pos as u32)
+                })
+                .map(|_| true)
+            }
             Self::LISTENING_MODE_NAME => {
                 ElemValueAccessor::<u32>::set_val(elem_value, || {
                     let pos = Self::LISTENING_MODES.iter()
@@ -19,6 +19,7 @@ use super::ch_strip_ctl::*;
 use super::reverb_ctl::*;
 use super::shell_ctl::*;
 use super::prog_ctl::*;
+use super::{TcKonnektSegmentsCtl, TcKonnektModel};
 
 #[derive(Default)]
 pub struct K24dModel{
@@ -38,10 +39,23 @@ pub struct K24dModel{
     knob2_ctl: ShellKnob2Ctl,
     prog_ctl: TcKonnektProgramCtl,
     specific_ctl: K24dSpecificCtl,
+    segments_ctl: TcKonnektSegmentsCtl,
 }
 
 const TIMEOUT_MS: u32 = 20;
 
+impl TcKonnektModel for K24dModel {
+    type Segments = K24dSegments;
+
+    fn segments(&self) -> &K24dSegments {
+        &self.segments
+    }
+
+    fn segments_ctl(&mut self) -> &mut TcKonnektSegmentsCtl {
+        &mut self.segments_ctl
+    }
+}
+
 impl CtlModel<SndDice> for K24dModel {
     fn load(&mut self, unit: &mut SndDice, card_cntr: &mut CardCntr) -> Result<(), Error> {
         let node = unit.get_node();
@@ -50,6 +64,7 @@ impl CtlModel<SndDice> for K24dModel {
         let caps = self.proto.read_clock_caps(&node, &self.sections, TIMEOUT_MS)?;
         let src_labels = self.proto.read_clock_source_labels(&node, &self.sections, TIMEOUT_MS)?;
         self.ctl.load(card_cntr, &caps, &src_labels)?;
+        self.ctl.cache_clock_state(unit, &self.proto, &self.sections, TIMEOUT_MS)?;
 
         self.ch_strip_ctl.load(unit, &self.proto, &mut self.segments.ch_strip_state,
                                &mut self.segments.ch_strip_meter, TIMEOUT_MS, card_cntr)?;
@@ -72,6 +87,7 @@ impl CtlModel<SndDice> for K24dModel {
         self.knob2_ctl.load(&self.segments.knob, card_cntr)?;
         self.prog_ctl.load(card_cntr)?;
         self.specific_ctl.load(card_cntr)?;
+        self.segments_ctl.load(card_cntr)?;
 
         Ok(())
     }
@@ -89,8 +105,8 @@ impl CtlModel<SndDice> for K24dModel {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments.hw_state, elem_id, elem_value)? {
             Ok(true)
-        } else if self.mixer_ctl.read(&self.segments.mixer_state, &self.segments.mixer_meter, elem_id,
-                                      elem_value)? {
+        } else if self.mixer_ctl.read(&self.segments.mixer_state, &self.segments.mixer_meter,
+                                      self.ctl.get_curr_rate_mode(), elem_id, elem_value)? {
             Ok(true)
         } else if self.reverb_return_ctl.read(&self.segments.mixer_state, elem_id, elem_value)? {
             Ok(true)
@@ -108,6 +124,8 @@ impl CtlModel<SndDice> for K24dModel {
             Ok(true)
         } else if self.specific_ctl.read(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -126,8 +144,8 @@ impl CtlModel<SndDice> for K24dModel {
             Ok(true)
         } else if self.hw_state_ctl.write(unit, &self.proto, &mut self.segments.hw_state, elem_id, new, TIMEOUT_MS)? {
             Ok(true)
-        } else if self.mixer_ctl.write(unit, &self.proto, &mut self.segments.mixer_state, elem_id,
-                                       old, new, TIMEOUT_MS)? {
+        } else if self.mixer_ctl.write(unit, &self.proto, &mut self.segments.mixer_state,
+                                       self.ctl.get_curr_rate_mode(), elem_id, old, new, TIMEOUT_MS)? {
             Ok(true)
         } else if self.reverb_return_ctl.write(unit, &self.proto, &mut self.segments.mixer_state, elem_id, new,
                                                TIMEOUT_MS)? {
@@ -153,10 +171,20 @@ impl CtlModel<SndDice> for K24dModel {
         } else if self.specific_ctl.write(unit, &self.proto, &mut self.segments, elem_id, new,
                                           TIMEOUT_MS)? {
             Ok(true)
+        } else if self.write_segments_ctl(unit, elem_id, new)? {
+            Ok(true)
         } else {
             Ok(false)
         }
     }
+
+    fn get_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        self.ch_strip_ctl.take_changed_elem_list(elem_id_list);
+        self.reverb_ctl.take_changed_elem_list(elem_id_list);
+        self.hw_state_ctl.take_changed_elem_list(elem_id_list);
+        self.mixer_ctl.take_changed_elem_list(elem_id_list);
+        self.take_refreshed_elem_list(elem_id_list);
+    }
 }
 
 impl NotifyModel<SndDice, u32> for K24dModel {
@@ -169,6 +197,7 @@ impl NotifyModel<SndDice, u32> for K24dModel {
         elem_id_list.extend_from_slice(&self.reverb_return_ctl.0);
         elem_id_list.extend_from_slice(&self.knob_ctl.notified_elem_list);
         elem_id_list.extend_from_slice(&self.prog_ctl.0);
+        elem_id_list.extend_from_slice(&self.segments_ctl.notified_elem_list);
     }
 
     fn parse_notification(&mut self, unit: &mut SndDice, msg: &u32) -> Result<(), Error> {
@@ -195,7 +224,8 @@ impl NotifyModel<SndDice, u32> for K24dModel {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments.hw_state, elem_id, elem_value)? {
             Ok(true)
-        } else if self.mixer_ctl.read_notified_elem(&self.segments.mixer_state, elem_id, elem_value)? {
+        } else if self.mixer_ctl.read_notified_elem(&self.segments.mixer_state, self.ctl.get_curr_rate_mode(),
+                                                    elem_id, elem_value)? {
             Ok(true)
         } else if self.reverb_return_ctl.read_notified_elem(&self.segments.mixer_state, elem_id, elem_value)? {
             Ok(true)
@@ -203,6 +233,8 @@ impl NotifyModel<SndDice, u32> for K24dModel {
             Ok(true)
         } else if self.prog_ctl.read(&self.segments.knob, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -215,6 +247,7 @@ impl MeasureModel<hinawa::SndDice> for K24dModel {
         elem_id_list.extend_from_slice(&self.ch_strip_ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.reverb_ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.mixer_ctl.measured_elem_list);
+        elem_id_list.extend_from_slice(&self.segments_ctl.measured_elem_list);
     }
 
     fn measure_states(&mut self, unit: &mut SndDice) -> Result<(), Error> {
@@ -224,6 +257,7 @@ impl MeasureModel<hinawa::SndDice> for K24dModel {
         self.reverb_ctl.measure_states(unit, &self.proto, &self.segments.reverb_state,
                                        &mut self.segments.reverb_meter, TIMEOUT_MS)?;
         self.proto.read_segment(&unit.get_node(), &mut self.segments.mixer_meter, TIMEOUT_MS)?;
+        self.segments_ctl.measure_states(unit, &self.proto, TIMEOUT_MS)?;
         Ok(())
     }
 
@@ -238,6 +272,8 @@ impl MeasureModel<hinawa::SndDice> for K24dModel {
             Ok(true)
         } else if self.mixer_ctl.read_measured_elem(&self.segments.mixer_meter, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.measure_elem(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
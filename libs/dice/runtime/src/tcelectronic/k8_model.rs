@@ -16,6 +16,7 @@ use dice_protocols::tcelectronic::shell::k8::*;
 
 use crate::common_ctl::*;
 use super::shell_ctl::*;
+use super::{TcKonnektSegmentsCtl, TcKonnektModel};
 
 #[derive(Default)]
 pub struct K8Model{
@@ -30,10 +31,23 @@ pub struct K8Model{
     knob_ctl: ShellKnobCtl,
     knob2_ctl: ShellKnob2Ctl,
     specific_ctl: K8SpecificCtl,
+    segments_ctl: TcKonnektSegmentsCtl,
 }
 
 const TIMEOUT_MS: u32 = 20;
 
+impl TcKonnektModel for K8Model {
+    type Segments = K8Segments;
+
+    fn segments(&self) -> &K8Segments {
+        &self.segments
+    }
+
+    fn segments_ctl(&mut self) -> &mut TcKonnektSegmentsCtl {
+        &mut self.segments_ctl
+    }
+}
+
 impl CtlModel<SndDice> for K8Model {
     fn load(&mut self, unit: &mut SndDice, card_cntr: &mut CardCntr) -> Result<(), Error> {
         let node = unit.get_node();
@@ -42,6 +56,7 @@ impl CtlModel<SndDice> for K8Model {
         let caps = self.proto.read_clock_caps(&node, &self.sections, TIMEOUT_MS)?;
         let src_labels = self.proto.read_clock_source_labels(&node, &self.sections, TIMEOUT_MS)?;
         self.ctl.load(card_cntr, &caps, &src_labels)?;
+        self.ctl.cache_clock_state(unit, &self.proto, &self.sections, TIMEOUT_MS)?;
 
         let node = unit.get_node();
         self.proto.read_segment(&node, &mut self.segments.hw_state, TIMEOUT_MS)?;
@@ -56,6 +71,7 @@ impl CtlModel<SndDice> for K8Model {
         self.knob_ctl.load(&self.segments.knob, card_cntr)?;
         self.knob2_ctl.load(&self.segments.knob, card_cntr)?;
         self.specific_ctl.load(card_cntr)?;
+        self.segments_ctl.load(card_cntr)?;
 
         Ok(())
     }
@@ -67,8 +83,8 @@ impl CtlModel<SndDice> for K8Model {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments.hw_state, elem_id, elem_value)? {
             Ok(true)
-        } else if self.mixer_ctl.read(&self.segments.mixer_state, &self.segments.mixer_meter, elem_id,
-                                      elem_value)? {
+        } else if self.mixer_ctl.read(&self.segments.mixer_state, &self.segments.mixer_meter,
+                                      self.ctl.get_curr_rate_mode(), elem_id, elem_value)? {
             Ok(true)
         } else if self.standalone_ctl.read(&self.segments.config, elem_id, elem_value)? {
             Ok(true)
@@ -80,6 +96,8 @@ impl CtlModel<SndDice> for K8Model {
             Ok(true)
         } else if self.specific_ctl.read(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -93,8 +111,8 @@ impl CtlModel<SndDice> for K8Model {
         } else if self.hw_state_ctl.write(unit, &self.proto, &mut self.segments.hw_state, elem_id,
                                           new, TIMEOUT_MS)? {
             Ok(true)
-        } else if self.mixer_ctl.write(unit, &self.proto, &mut self.segments.mixer_state, elem_id,
-                                       old, new, TIMEOUT_MS)? {
+        } else if self.mixer_ctl.write(unit, &self.proto, &mut self.segments.mixer_state,
+                                       self.ctl.get_curr_rate_mode(), elem_id, old, new, TIMEOUT_MS)? {
             Ok(true)
         } else if self.standalone_ctl.write(unit, &self.proto, &mut self.segments.config, elem_id, new,
                                             TIMEOUT_MS)? {
@@ -111,10 +129,18 @@ impl CtlModel<SndDice> for K8Model {
         } else if self.specific_ctl.write(unit, &self.proto, &mut self.segments, elem_id, new,
                                           TIMEOUT_MS)? {
             Ok(true)
+        } else if self.write_segments_ctl(unit, elem_id, new)? {
+            Ok(true)
         } else {
             Ok(false)
         }
     }
+
+    fn get_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        self.hw_state_ctl.take_changed_elem_list(elem_id_list);
+        self.mixer_ctl.take_changed_elem_list(elem_id_list);
+        self.take_refreshed_elem_list(elem_id_list);
+    }
 }
 
 impl NotifyModel<SndDice, u32> for K8Model {
@@ -124,6 +150,7 @@ impl NotifyModel<SndDice, u32> for K8Model {
         elem_id_list.extend_from_slice(&self.mixer_ctl.notified_elem_list);
         elem_id_list.extend_from_slice(&self.knob_ctl.notified_elem_list);
         elem_id_list.extend_from_slice(&self.specific_ctl.0);
+        elem_id_list.extend_from_slice(&self.segments_ctl.notified_elem_list);
     }
 
     fn parse_notification(&mut self, unit: &mut SndDice, msg: &u32) -> Result<(), Error> {
@@ -144,12 +171,15 @@ impl NotifyModel<SndDice, u32> for K8Model {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments.hw_state, elem_id, elem_value)? {
             Ok(true)
-        } else if self.mixer_ctl.read_notified_elem(&self.segments.mixer_state, elem_id, elem_value)? {
+        } else if self.mixer_ctl.read_notified_elem(&self.segments.mixer_state, self.ctl.get_curr_rate_mode(),
+                                                    elem_id, elem_value)? {
             Ok(true)
         } else if self.knob_ctl.read(&self.segments.knob, elem_id, elem_value)? {
             Ok(true)
         } else if self.specific_ctl.read_notified_elem(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -160,12 +190,14 @@ impl MeasureModel<hinawa::SndDice> for K8Model {
     fn get_measure_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
         elem_id_list.extend_from_slice(&self.ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.mixer_ctl.measured_elem_list);
+        elem_id_list.extend_from_slice(&self.segments_ctl.measured_elem_list);
     }
 
     fn measure_states(&mut self, unit: &mut SndDice) -> Result<(), Error> {
         self.ctl.measure_states(unit, &self.proto, &self.sections, TIMEOUT_MS)?;
 
         self.proto.read_segment(&unit.get_node(), &mut self.segments.mixer_meter, TIMEOUT_MS)?;
+        self.segments_ctl.measure_states(unit, &self.proto, TIMEOUT_MS)?;
         Ok(())
     }
 
@@ -176,6 +208,8 @@ impl MeasureModel<hinawa::SndDice> for K8Model {
             Ok(true)
         } else if self.mixer_ctl.read_measured_elem(&self.segments.mixer_meter, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.measure_elem(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
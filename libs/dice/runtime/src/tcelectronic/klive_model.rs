@@ -20,6 +20,7 @@ use super::reverb_ctl::*;
 use super::shell_ctl::*;
 use super::midi_send_ctl::*;
 use super::prog_ctl::*;
+use super::{TcKonnektSegmentsCtl, TcKonnektModel};
 
 #[derive(Default)]
 pub struct KliveModel{
@@ -41,10 +42,23 @@ pub struct KliveModel{
     knob2_ctl: ShellKnob2Ctl,
     prog_ctl: TcKonnektProgramCtl,
     specific_ctl: KliveSpecificCtl,
+    segments_ctl: TcKonnektSegmentsCtl,
 }
 
 const TIMEOUT_MS: u32 = 20;
 
+impl TcKonnektModel for KliveModel {
+    type Segments = KliveSegments;
+
+    fn segments(&self) -> &KliveSegments {
+        &self.segments
+    }
+
+    fn segments_ctl(&mut self) -> &mut TcKonnektSegmentsCtl {
+        &mut self.segments_ctl
+    }
+}
+
 impl CtlModel<SndDice> for KliveModel {
     fn load(&mut self, unit: &mut SndDice, card_cntr: &mut CardCntr) -> Result<(), Error> {
         let node = unit.get_node();
@@ -53,6 +67,7 @@ impl CtlModel<SndDice> for KliveModel {
         let caps = self.proto.read_clock_caps(&node, &self.sections, TIMEOUT_MS)?;
         let src_labels = self.proto.read_clock_source_labels(&node, &self.sections, TIMEOUT_MS)?;
         self.ctl.load(card_cntr, &caps, &src_labels)?;
+        self.ctl.cache_clock_state(unit, &self.proto, &self.sections, TIMEOUT_MS)?;
 
         self.ch_strip_ctl.load(unit, &self.proto, &mut self.segments.ch_strip_state,
                                &mut self.segments.ch_strip_meter, TIMEOUT_MS, card_cntr)?;
@@ -77,6 +92,7 @@ impl CtlModel<SndDice> for KliveModel {
         self.knob2_ctl.load(&self.segments.knob, card_cntr)?;
         self.prog_ctl.load(card_cntr)?;
         self.specific_ctl.load(card_cntr)?;
+        self.segments_ctl.load(card_cntr)?;
 
         Ok(())
     }
@@ -94,8 +110,8 @@ impl CtlModel<SndDice> for KliveModel {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments.hw_state, elem_id, elem_value)? {
             Ok(true)
-        } else if self.mixer_ctl.read(&self.segments.mixer_state, &self.segments.mixer_meter, elem_id,
-                                      elem_value)? {
+        } else if self.mixer_ctl.read(&self.segments.mixer_state, &self.segments.mixer_meter,
+                                      self.ctl.get_curr_rate_mode(), elem_id, elem_value)? {
             Ok(true)
         } else if self.reverb_return_ctl.read(&self.segments.mixer_state, elem_id, elem_value)? {
             Ok(true)
@@ -117,6 +133,8 @@ impl CtlModel<SndDice> for KliveModel {
             Ok(true)
         } else if self.specific_ctl.read(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -125,6 +143,10 @@ impl CtlModel<SndDice> for KliveModel {
     fn write(&mut self, unit: &mut SndDice, elem_id: &ElemId, old: &ElemValue, new: &ElemValue)
         -> Result<bool, Error>
     {
+        // The mode of channel strip effect can be changed by the other ways.
+        let riaa_mode = self.segments.mixer_state.data.ch_strip_mode != ChStripMode::FabrikC;
+        self.ch_strip_ctl.cache_riaa_mode(riaa_mode);
+
         if self.ctl.write(unit, &self.proto, &self.sections, elem_id, old, new, TIMEOUT_MS)? {
             Ok(true)
         } else if self.ch_strip_ctl.write(unit, &self.proto, &mut self.segments.ch_strip_state, elem_id,
@@ -136,8 +158,8 @@ impl CtlModel<SndDice> for KliveModel {
         } else if self.hw_state_ctl.write(unit, &self.proto, &mut self.segments.hw_state, elem_id,
                                           new, TIMEOUT_MS)? {
             Ok(true)
-        } else if self.mixer_ctl.write(unit, &self.proto, &mut self.segments.mixer_state, elem_id,
-                                       old, new, TIMEOUT_MS)? {
+        } else if self.mixer_ctl.write(unit, &self.proto, &mut self.segments.mixer_state,
+                                       self.ctl.get_curr_rate_mode(), elem_id, old, new, TIMEOUT_MS)? {
             Ok(true)
         } else if self.reverb_return_ctl.write(unit, &self.proto, &mut self.segments.mixer_state, elem_id,
                                                new, TIMEOUT_MS)? {
@@ -169,10 +191,21 @@ impl CtlModel<SndDice> for KliveModel {
         } else if self.specific_ctl.write(unit, &self.proto, &mut self.segments, elem_id, old, new,
                                           TIMEOUT_MS)? {
             Ok(true)
+        } else if self.write_segments_ctl(unit, elem_id, new)? {
+            Ok(true)
         } else {
             Ok(false)
         }
     }
+
+    fn get_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        self.ch_strip_ctl.take_changed_elem_list(elem_id_list);
+        self.reverb_ctl.take_changed_elem_list(elem_id_list);
+        self.hw_state_ctl.take_changed_elem_list(elem_id_list);
+        self.mixer_ctl.take_changed_elem_list(elem_id_list);
+        self.specific_ctl.take_changed_elem_list(elem_id_list);
+        self.take_refreshed_elem_list(elem_id_list);
+    }
 }
 
 impl NotifyModel<SndDice, u32> for KliveModel {
@@ -185,6 +218,8 @@ impl NotifyModel<SndDice, u32> for KliveModel {
         elem_id_list.extend_from_slice(&self.reverb_return_ctl.0);
         elem_id_list.extend_from_slice(&self.knob_ctl.notified_elem_list);
         elem_id_list.extend_from_slice(&self.prog_ctl.0);
+        elem_id_list.extend_from_slice(&self.specific_ctl.notified_elem_list);
+        elem_id_list.extend_from_slice(&self.segments_ctl.notified_elem_list);
     }
 
     fn parse_notification(&mut self, unit: &mut SndDice, msg: &u32) -> Result<(), Error> {
@@ -211,7 +246,8 @@ impl NotifyModel<SndDice, u32> for KliveModel {
             Ok(true)
         } else if self.hw_state_ctl.read(&self.segments.hw_state, elem_id, elem_value)? {
             Ok(true)
-        } else if self.mixer_ctl.read_notified_elem(&self.segments.mixer_state, elem_id, elem_value)? {
+        } else if self.mixer_ctl.read_notified_elem(&self.segments.mixer_state, self.ctl.get_curr_rate_mode(),
+                                                    elem_id, elem_value)? {
             Ok(true)
         } else if self.reverb_return_ctl.read_notified_elem(&self.segments.mixer_state, elem_id, elem_value)? {
             Ok(true)
@@ -219,6 +255,10 @@ impl NotifyModel<SndDice, u32> for KliveModel {
             Ok(true)
         } else if self.prog_ctl.read(&self.segments.knob, elem_id, elem_value)? {
             Ok(true)
+        } else if self.specific_ctl.read(&self.segments, elem_id, elem_value)? {
+            Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -231,6 +271,7 @@ impl MeasureModel<hinawa::SndDice> for KliveModel {
         elem_id_list.extend_from_slice(&self.ch_strip_ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.reverb_ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.mixer_ctl.measured_elem_list);
+        elem_id_list.extend_from_slice(&self.segments_ctl.measured_elem_list);
     }
 
     fn measure_states(&mut self, unit: &mut SndDice) -> Result<(), Error> {
@@ -240,6 +281,8 @@ impl MeasureModel<hinawa::SndDice> for KliveModel {
         self.reverb_ctl.measure_states(unit, &self.proto, &self.segments.reverb_state,
                                        &mut self.segments.reverb_meter, TIMEOUT_MS)?;
         self.proto.read_segment(&unit.get_node(), &mut self.segments.mixer_meter, TIMEOUT_MS)?;
+        self.segments.mixer_meter.data.mask_unavailable_digital_inputs(self.ctl.get_curr_rate_mode());
+        self.segments_ctl.measure_states(unit, &self.proto, TIMEOUT_MS)?;
         Ok(())
     }
 
@@ -254,6 +297,8 @@ impl MeasureModel<hinawa::SndDice> for KliveModel {
             Ok(true)
         } else if self.mixer_ctl.read_measured_elem(&self.segments.mixer_meter, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.measure_elem(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -299,15 +344,21 @@ fn ch_strip_mode_to_string(mode: &ChStripMode) -> String {
 }
 
 #[derive(Default, Debug)]
-struct KliveSpecificCtl;
+struct KliveSpecificCtl{
+    out_pair_link: bool,
+    notified_elem_list: Vec<ElemId>,
+    changed_elem_list: Vec<ElemId>,
+}
 
 impl KliveSpecificCtl {
     const OUTPUT_IMPEDANCE_NAME: &'static str = "output-impedance";
     const OUT_01_SRC_NAME: &'static str = "output-1/2-source";
     const OUT_23_SRC_NAME: &'static str = "output-3/4-source";
+    const OUT_PAIR_LINK_NAME: &'static str = "output-pair-link";
     const USE_CH_STRIP_AS_PLUGIN_NAME: &'static str = "use-channel-strip-as-plugin";
     const CH_STRIP_SRC_NAME: &'static str = "channel-strip-source";
     const CH_STRIP_MODE_NAME: &'static str = "channel-strip-mode";
+    const CH_STRIP_FABRIK_C_PARAMS_ACTIVE_NAME: &'static str = "channel-strip-deesser-limitter-active";
     const USE_REVERB_AT_MID_RATE: &'static str = "use-reverb-at-mid-rate";
     const MIXER_ENABLE_NAME: &'static str = "mixer-enable";
 
@@ -340,10 +391,17 @@ impl KliveSpecificCtl {
             .map(|s| phys_out_src_to_string(s))
             .collect();
         let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::OUT_01_SRC_NAME, 0);
-        let _ = card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)?;
+        card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::OUT_23_SRC_NAME, 0);
-        let _ = card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)?;
+        card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
+
+        // NOTE: the link is not stored in the device. The source of output 3/4 follows the one of
+        // output 1/2 and vice versa while enabled.
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::OUT_PAIR_LINK_NAME, 0);
+        let _ = card_cntr.add_bool_elems(&elem_id, 1, 1, true)?;
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::USE_CH_STRIP_AS_PLUGIN_NAME, 0);
         let _ = card_cntr.add_bool_elems(&elem_id, 1, 1, true)?;
@@ -358,7 +416,14 @@ impl KliveSpecificCtl {
             .map(|s| ch_strip_mode_to_string(s))
             .collect();
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::CH_STRIP_MODE_NAME, 0);
-        let _ = card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)?;
+        card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
+
+        // NOTE: the parameters of deesser and limitter are just available in FabrikC mode.
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0,
+                                          Self::CH_STRIP_FABRIK_C_PARAMS_ACTIVE_NAME, 0);
+        card_cntr.add_bool_elems(&elem_id, 1, 1, false)
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, Self::USE_REVERB_AT_MID_RATE, 0);
         let _ = card_cntr.add_bool_elems(&elem_id, 1, 1, true)?;
@@ -400,6 +465,10 @@ impl KliveSpecificCtl {
                 })
                 .map(|_| true)
             }
+            Self::OUT_PAIR_LINK_NAME => {
+                ElemValueAccessor::<bool>::set_val(elem_value, || Ok(self.out_pair_link))
+                .map(|_| true)
+            }
             Self::USE_CH_STRIP_AS_PLUGIN_NAME => {
                 ElemValueAccessor::<bool>::set_val(elem_value, || {
                     Ok(segments.mixer_state.data.use_ch_strip_as_plugin)
@@ -424,6 +493,12 @@ impl KliveSpecificCtl {
                 })
                 .map(|_| true)
             }
+            Self::CH_STRIP_FABRIK_C_PARAMS_ACTIVE_NAME => {
+                ElemValueAccessor::<bool>::set_val(elem_value, || {
+                    Ok(segments.mixer_state.data.ch_strip_mode == ChStripMode::FabrikC)
+                })
+                .map(|_| true)
+            }
             Self::USE_REVERB_AT_MID_RATE => {
                 ElemValueAccessor::<bool>::set_val(elem_value, || {
                     Ok(segments.mixer_state.data.use_reverb_at_mid_rate)
@@ -474,8 +549,16 @@ impl KliveSpecificCtl {
                         })
                         .and_then(|&s| {
                             segments.config.data.out_01_src = s;
+                            if self.out_pair_link {
+                                segments.config.data.out_23_src = s;
+                            }
                             proto.write_segment(&unit.get_node(), &mut segments.config, timeout_ms)
                         })
+                        .map(|_| {
+                            if self.out_pair_link {
+                                self.push_changed_elem(Self::OUT_23_SRC_NAME);
+                            }
+                        })
                 })
                 .map(|_| true)
             }
@@ -489,8 +572,28 @@ impl KliveSpecificCtl {
                         })
                         .and_then(|&s| {
                             segments.config.data.out_23_src = s;
+                            if self.out_pair_link {
+                                segments.config.data.out_01_src = s;
+                            }
                             proto.write_segment(&unit.get_node(), &mut segments.config, timeout_ms)
                         })
+                        .map(|_| {
+                            if self.out_pair_link {
+                                self.push_changed_elem(Self::OUT_01_SRC_NAME);
+                            }
+                        })
+                })
+                .map(|_| true)
+            }
+            Self::OUT_PAIR_LINK_NAME => {
+                ElemValueAccessor::<bool>::get_val(new, |val| {
+                    self.out_pair_link = val;
+                    if val && segments.config.data.out_23_src != segments.config.data.out_01_src {
+                        segments.config.data.out_23_src = segments.config.data.out_01_src;
+                        proto.write_segment(&unit.get_node(), &mut segments.config, timeout_ms)?;
+                        self.push_changed_elem(Self::OUT_23_SRC_NAME);
+                    }
+                    Ok(())
                 })
                 .map(|_| true)
             }
@@ -548,4 +651,14 @@ impl KliveSpecificCtl {
             _ => Ok(false),
         }
     }
+
+    // The source of the other pair is changed as well while linked.
+    fn push_changed_elem(&mut self, name: &str) {
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, name, 0);
+        self.changed_elem_list.push(elem_id);
+    }
+
+    fn take_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        elem_id_list.append(&mut self.changed_elem_list);
+    }
 }
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2020 Takashi Sakamoto
+
+//! One-shot element to read all of segments again for the case that any notification is lost.
+
+use glib::Error;
+
+use alsactl::{ElemId, ElemIfaceType, ElemValue};
+
+use core::card_cntr::*;
+use core::elem_value_accessor::*;
+
+const REFRESH_NAME: &str = "refresh";
+
+#[derive(Default, Debug)]
+pub struct RefreshCtl {
+    pub notified_elem_list: Vec<ElemId>,
+    refreshed: bool,
+}
+
+impl RefreshCtl {
+    pub fn load(&mut self, card_cntr: &mut CardCntr) -> Result<(), Error> {
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, REFRESH_NAME, 0);
+        card_cntr.add_bool_elems(&elem_id, 1, 1, true)
+            .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))
+    }
+
+    pub fn read(&self, elem_id: &ElemId, elem_value: &mut ElemValue) -> Result<bool, Error> {
+        match elem_id.get_name().as_str() {
+            REFRESH_NAME => {
+                // The operation is one-shot.
+                ElemValueAccessor::<bool>::set_val(elem_value, || Ok(false))?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Return whether to read all of segments again when the element is the target, else None.
+    pub fn write(&mut self, elem_id: &ElemId, new: &ElemValue) -> Result<Option<bool>, Error> {
+        match elem_id.get_name().as_str() {
+            REFRESH_NAME => {
+                let mut requested = false;
+                ElemValueAccessor::<bool>::get_val(new, |val| {
+                    requested = val;
+                    Ok(())
+                })?;
+                self.refreshed = requested;
+                Ok(Some(requested))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Return whether all of segments were read again by the last write operation, and clear it.
+    pub fn take_refreshed(&mut self) -> bool {
+        std::mem::take(&mut self.refreshed)
+    }
+}
@@ -56,6 +56,7 @@ fn create_reverb_algorithm_labels() -> Vec<String> {
 pub struct ReverbCtl {
     pub notified_elem_list: Vec<ElemId>,
     pub measured_elem_list: Vec<ElemId>,
+    changed_elem_list: Vec<ElemId>,
 }
 
 impl ReverbCtl {
@@ -77,6 +78,7 @@ impl ReverbCtl {
     const REVERB_ALGORITHM_NAME: &'static str = "reverb-algorithm";
     const REVERB_OUTPUT_METER_NAME: &'static str = "reverb-output-meter";
     const REVERB_INPUT_METER_NAME: &'static str = "reverb-input-meter";
+    const REVERB_RESET_NAME: &'static str = "reverb-reset";
 
     const INPUT_LEVEL_MIN: i32 = -240;
     const INPUT_LEVEL_MAX: i32 = 0;
@@ -179,6 +181,8 @@ impl ReverbCtl {
         let labels = create_reverb_algorithm_labels();
         self.state_add_enum_elem(card_cntr, Self::REVERB_ALGORITHM_NAME, 1, &labels, true)?;
 
+        self.state_add_bool_elem(card_cntr, Self::REVERB_RESET_NAME, 1, true)?;
+
         self.meter_add_int_elem(card_cntr, Self::REVERB_OUTPUT_METER_NAME,
                                 Self::METER_OUTPUT_MIN, Self::METER_OUTPUT_MAX, Self::METER_OUTPUT_STEP,
                                 2, Some(&Into::<Vec<u32>>::into(Self::METER_OUTPUT_TLV)), false)?;
@@ -327,10 +331,35 @@ impl ReverbCtl {
                 self.state_write_elem(unit, proto, segment, elem_value, timeout_ms,
                                            |state, val: u32| state.algorithm = ReverbAlgorithm::from(val))
             }
+            Self::REVERB_RESET_NAME => {
+                ElemValueAccessor::<bool>::get_val(elem_value, |val| {
+                    if !val {
+                        return Ok(());
+                    }
+
+                    // NOTE: no command is known to reset the state of DSP. Bypass the effect
+                    // once, then restore current parameters. The whole segment is written so that
+                    // the device receives the transactions even if the effect is already bypassed.
+                    let state = *segment.data.as_mut();
+                    segment.data.as_mut().bypass = true;
+                    let node = unit.get_node();
+                    let res = proto.write_whole_segment(&node, segment, timeout_ms);
+                    *segment.data.as_mut() = state;
+                    res.and_then(|_| proto.write_whole_segment(&node, segment, timeout_ms))
+                })?;
+                // The operation is one-shot.
+                self.changed_elem_list.push(elem_id.clone());
+                Ok(true)
+            }
             _ => Ok(false),
         }
     }
 
+    /// Retrieve the list of elements of which value is changed by the last write operation.
+    pub fn take_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        elem_id_list.append(&mut self.changed_elem_list);
+    }
+
     fn state_write_elem<T, S, U, F>(&mut self, unit: &SndDice, proto: &T, segment: &mut TcKonnektSegment<S>,
                                     elem_value: &ElemValue, timeout_ms: u32, cb: F)
         -> Result<bool, Error>
@@ -404,6 +433,11 @@ impl ReverbCtl {
             Self::REVERB_ALGORITHM_NAME => {
                 self.state_read_elem(segment, elem_value, |state| u32::from(state.algorithm))
             }
+            Self::REVERB_RESET_NAME => {
+                // The operation is one-shot.
+                ElemValueAccessor::<bool>::set_val(elem_value, || Ok(false))
+                .map(|_| true)
+            }
             _ => Ok(false),
         }
     }
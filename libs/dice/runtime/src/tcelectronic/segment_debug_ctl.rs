@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2020 Takashi Sakamoto
+
+//! Elements to expose raw quadlets in segments for reverse-engineering.
+//!
+//! The elements are available when the crate is built with `tc-segment-debug` feature. The
+//! quadlets to expose are given by `TC_SEGMENT_DEBUG_OFFSETS` environment variable at build time,
+//! as comma-separated hexadecimal offsets relative to the base of segments; e.g. `0x0000,0x0a14`.
+//! The offsets are checked by build script, thus the build fails for invalid offsets.
+
+use glib::Error;
+
+use alsactl::{ElemId, ElemIfaceType, ElemValue};
+
+use hinawa::{FwNode, SndDice, SndUnitExt};
+
+use dice_protocols::tcat::*;
+use dice_protocols::tcelectronic::TC_KONNEKT_SEGMENT_BASE_OFFSET;
+
+use core::card_cntr::*;
+use core::elem_value_accessor::*;
+
+include!(concat!(env!("OUT_DIR"), "/segment_debug_offsets.rs"));
+
+#[derive(Default, Debug)]
+pub struct SegmentDebugCtl {
+    pub measured_elem_list: Vec<ElemId>,
+    vals: Vec<i32>,
+}
+
+impl SegmentDebugCtl {
+    const QUADLET_NAME: &'static str = "segment-debug-quadlet";
+
+    pub fn load(&mut self, card_cntr: &mut CardCntr) -> Result<(), Error> {
+        if OFFSETS.len() > 0 {
+            self.vals = vec![0;OFFSETS.len()];
+
+            let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, Self::QUADLET_NAME, 0);
+            card_cntr.add_int_elems(&elem_id, 1, i32::MIN, i32::MAX, 1, OFFSETS.len(), None, false)
+                .map(|mut elem_id_list| self.measured_elem_list.append(&mut elem_id_list))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn measure_states<T>(&mut self, unit: &SndDice, proto: &T, timeout_ms: u32) -> Result<(), Error>
+        where T: GeneralProtocol<FwNode>,
+    {
+        let node = unit.get_node();
+        let mut quadlet = [0;4];
+        OFFSETS.iter()
+            .zip(self.vals.iter_mut())
+            .try_for_each(|(&offset, val)| {
+                proto.read(&node, TC_KONNEKT_SEGMENT_BASE_OFFSET + offset, &mut quadlet, timeout_ms)
+                    .map(|_| *val = i32::from_be_bytes(quadlet))
+            })
+    }
+
+    pub fn measure_elem(&self, elem_id: &ElemId, elem_value: &mut ElemValue) -> Result<bool, Error> {
+        match elem_id.get_name().as_str() {
+            Self::QUADLET_NAME => {
+                ElemValueAccessor::<i32>::set_vals(elem_value, self.vals.len(), |idx| {
+                    Ok(self.vals[idx])
+                })
+                .map(|_| true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
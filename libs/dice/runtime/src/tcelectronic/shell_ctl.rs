@@ -9,6 +9,7 @@ use hinawa::{FwNode, SndDice, SndUnitExt};
 
 use alsa_ctl_tlv_codec::items::DbInterval;
 
+use dice_protocols::tcat::extension::cmd_section::RateMode;
 use dice_protocols::tcelectronic::*;
 use dice_protocols::tcelectronic::fw_led::*;
 use dice_protocols::tcelectronic::shell::*;
@@ -17,6 +18,9 @@ use dice_protocols::tcelectronic::standalone::*;
 use core::card_cntr::*;
 use core::elem_value_accessor::*;
 
+use crate::ctl_error::*;
+use crate::meter_scale::*;
+
 use super::fw_led_ctl::*;
 use super::standalone_ctl::*;
 
@@ -57,7 +61,7 @@ impl HwStateCtl {
             .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
 
         self.fw_led_ctl.load(card_cntr)?;
-        self.notified_elem_list.extend_from_slice(&self.fw_led_ctl.0);
+        self.notified_elem_list.extend_from_slice(&self.fw_led_ctl.notified_elem_list);
 
         Ok(())
     }
@@ -90,12 +94,21 @@ impl HwStateCtl {
     {
         self.fw_led_ctl.write(unit, proto, segment, elem_id, elem_value, timeout_ms)
     }
+
+    pub fn take_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        self.fw_led_ctl.take_changed_elem_list(elem_id_list);
+    }
 }
 
 #[derive(Default, Debug)]
 pub struct ShellMixerCtl{
     pub notified_elem_list: Vec<ElemId>,
     pub measured_elem_list: Vec<ElemId>,
+    // The volume of mixer output before muting whole monitor output, to restore it at unmute.
+    unmuted_output_volume: Option<i32>,
+    // The pans of monitor sources before monaural mixer output, to restore them at stereo.
+    stereo_pans: Option<Vec<(i32, i32)>>,
+    changed_elem_list: Vec<ElemId>,
 }
 
 impl ShellMixerCtl {
@@ -114,6 +127,9 @@ impl ShellMixerCtl {
     const MIXER_OUT_DIM_NAME: &'static str = "mixer-out-dim-enable";
     const MIXER_OUT_VOL_NAME: &'static str = "mixer-out-volume";
     const MIXER_OUT_DIM_VOL_NAME: &'static str = "mixer-out-dim-volume";
+    const MIXER_OUT_MONO_NAME: &'static str = "output-mono";
+
+    const MONITOR_MUTE_NAME: &'static str = "monitor-mute";
 
     const STREAM_IN_METER_NAME: &'static str = "stream-input-meters";
     const ANALOG_IN_METER_NAME: &'static str = "analog-input-meters";
@@ -125,6 +141,13 @@ impl ShellMixerCtl {
     const LEVEL_STEP: i32 = 1;
     const LEVEL_TLV: DbInterval = DbInterval{min: -9400, max: 0, linear: false, mute_avail: false};
 
+    const METER_SCALE: MeterScale = MeterScale{
+        min: Self::LEVEL_MIN,
+        max: Self::LEVEL_MAX,
+        step: Self::LEVEL_STEP,
+        tlv: Self::LEVEL_TLV,
+    };
+
     const PAN_MIN: i32 = -50;
     const PAN_MAX: i32 = 50;
     const PAN_STEP: i32 = 1;
@@ -170,6 +193,11 @@ impl ShellMixerCtl {
         self.state_add_elem_bool(card_cntr, Self::MIXER_OUT_DIM_NAME, 1)?;
         self.state_add_elem_level(card_cntr, Self::MIXER_OUT_VOL_NAME, 1)?;
         self.state_add_elem_level(card_cntr, Self::MIXER_OUT_DIM_VOL_NAME, 1)?;
+        self.state_add_elem_bool(card_cntr, Self::MIXER_OUT_MONO_NAME, 1)?;
+
+        // For mute of whole monitor output. The dim is available by the elements for output of
+        // mixer.
+        self.state_add_elem_bool(card_cntr, Self::MONITOR_MUTE_NAME, 1)?;
 
         // For meter.
         let labels = (0..meter_segment.data.as_ref().stream_inputs.len())
@@ -225,20 +253,19 @@ impl ShellMixerCtl {
         -> Result<(), Error>
     {
         let elem_id = alsactl::ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, name, 0);
-        card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                value_count, Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)), false)
+        Self::METER_SCALE.add_int_elems(card_cntr, &elem_id, 1, value_count)
             .map(|mut elem_id_list| self.measured_elem_list.append(&mut elem_id_list))
     }
 
     pub fn read<S, M>(&self, state_segment: &TcKonnektSegment<S>, meter_segment: &TcKonnektSegment<M>,
-                      elem_id: &ElemId, elem_value: &mut ElemValue)
+                      rate_mode: RateMode, elem_id: &ElemId, elem_value: &mut ElemValue)
         -> Result<bool, Error>
-        where S: TcKonnektSegmentData + AsRef<ShellMixerState>,
+        where S: TcKonnektSegmentData + ShellMixerConvert,
               TcKonnektSegment<S>: TcKonnektSegmentSpec,
               M: TcKonnektSegmentData + AsRef<ShellMixerMeter>,
               TcKonnektSegment<M>: TcKonnektSegmentSpec,
     {
-        if self.read_notified_elem(state_segment, elem_id, elem_value)? {
+        if self.read_notified_elem(state_segment, rate_mode, elem_id, elem_value)? {
             Ok(true)
         } else if self.read_measured_elem(meter_segment, elem_id, elem_value)? {
             Ok(true)
@@ -247,11 +274,22 @@ impl ShellMixerCtl {
         }
     }
 
+    /// Detect availability of each pair of physical sources at the rate mode. The pairs of analog
+    /// sources are always available.
+    fn get_phys_src_pair_availability<S>(segment: &TcKonnektSegment<S>, rate_mode: RateMode) -> Vec<bool>
+        where S: TcKonnektSegmentData + ShellMixerConvert,
+              TcKonnektSegment<S>: TcKonnektSegmentSpec,
+    {
+        let mut avail_list = vec![true; segment.data.as_ref().analog.len()];
+        avail_list.append(&mut S::get_digital_input_pair_availability(rate_mode));
+        avail_list
+    }
+
     fn state_read_phys_src<S, T, F>(segment: &TcKonnektSegment<S>, elem_value: &mut ElemValue, cb: F)
         -> Result<bool, Error>
         where S: TcKonnektSegmentData + AsRef<ShellMixerState>,
               TcKonnektSegment<S>: TcKonnektSegmentSpec,
-              F: Fn(&MonitorSrcParam) -> Result<T, Error>,
+              F: Fn(usize, &MonitorSrcParam) -> Result<T, Error>,
               T: Default + Copy + Eq,
               ElemValue: ElemValueAccessor<T>,
     {
@@ -272,16 +310,17 @@ impl ShellMixerCtl {
             } else {
                 &src_pair.right
             };
-            cb(param)
+            cb(i, param)
         })
         .map(|_| true)
     }
 
     pub fn write<T, S>(&mut self, unit: &SndDice, proto: &T, segment: &mut TcKonnektSegment<S>,
-                       elem_id: &ElemId, old: &ElemValue, new: &ElemValue, timeout_ms: u32)
+                       rate_mode: RateMode, elem_id: &ElemId, old: &ElemValue, new: &ElemValue,
+                       timeout_ms: u32)
         -> Result<bool, Error>
         where T: TcKonnektSegmentProtocol<FwNode, S>,
-              S: TcKonnektSegmentData + AsRef<ShellMixerState> + AsMut<ShellMixerState>,
+              S: TcKonnektSegmentData + ShellMixerConvert,
               TcKonnektSegment<S>: TcKonnektSegmentSpec,
     {
         match elem_id.get_name().as_str() {
@@ -292,6 +331,7 @@ impl ShellMixerCtl {
                 })
             }
             Self::MIXER_STREAM_SRC_PAIR_PAN_NAME => {
+                self.check_stereo()?;
                 Self::state_write(unit, proto, segment, new, timeout_ms, |state, val| {
                     state.stream.left.pan_to_mixer = val;
                     Ok(())
@@ -325,13 +365,25 @@ impl ShellMixerCtl {
                 .map(|_| true)
             }
             Self::MIXER_PHYS_SRC_GAIN_NAME => {
-                Self::state_write_phys_src(unit, proto, segment, new, old, timeout_ms, |param, val| {
-                    param.gain_to_mixer = val;
+                let avail_list = Self::get_phys_src_pair_availability(segment, rate_mode);
+                Self::state_write_phys_src(unit, proto, segment, new, old, timeout_ms, true, |i, param, val| {
+                    if avail_list[i] {
+                        param.gain_to_mixer = val;
+                    } else {
+                        Self::warn_inactive_phys_src(i, rate_mode);
+                    }
                     Ok(())
                 })
+                .map(|res| {
+                    // The value of the other channel in stereo-linked pair is changed as well, and
+                    // the value for inactive pair is restored.
+                    self.changed_elem_list.push(elem_id.clone());
+                    res
+                })
             }
             Self::MIXER_PHYS_SRC_PAN_NAME => {
-                Self::state_write_phys_src(unit, proto, segment, new, old, timeout_ms, |param, val| {
+                self.check_stereo()?;
+                Self::state_write_phys_src(unit, proto, segment, new, old, timeout_ms, false, |_, param, val| {
                     param.pan_to_mixer = val;
                     Ok(())
                 })
@@ -352,10 +404,21 @@ impl ShellMixerCtl {
                 .map(|_| true)
             }
             Self::REVERB_PHYS_SRC_GAIN_NAME => {
-                Self::state_write_phys_src(unit, proto, segment, new, old, timeout_ms, |param, val| {
-                    param.gain_to_send = val;
+                let avail_list = Self::get_phys_src_pair_availability(segment, rate_mode);
+                Self::state_write_phys_src(unit, proto, segment, new, old, timeout_ms, true, |i, param, val| {
+                    if avail_list[i] {
+                        param.gain_to_send = val;
+                    } else {
+                        Self::warn_inactive_phys_src(i, rate_mode);
+                    }
                     Ok(())
                 })
+                .map(|res| {
+                    // The value of the other channel in stereo-linked pair is changed as well, and
+                    // the value for inactive pair is restored.
+                    self.changed_elem_list.push(elem_id.clone());
+                    res
+                })
             }
             Self::MIXER_OUT_DIM_NAME => {
                 Self::state_write(unit, proto, segment, new, timeout_ms, |state, val| {
@@ -368,6 +431,13 @@ impl ShellMixerCtl {
                     state.output_volume = val;
                     Ok(())
                 })
+                .map(|res| {
+                    // The change of volume releases the mute.
+                    if self.unmuted_output_volume.take().is_some() {
+                        self.push_changed_elem(Self::MONITOR_MUTE_NAME);
+                    }
+                    res
+                })
             }
             Self::MIXER_OUT_DIM_VOL_NAME => {
                 Self::state_write(unit, proto, segment, new, timeout_ms, |state, val| {
@@ -375,10 +445,74 @@ impl ShellMixerCtl {
                     Ok(())
                 })
             }
+            Self::MIXER_OUT_MONO_NAME => {
+                let mut stereo_pans = self.stereo_pans.clone();
+                ElemValueAccessor::<bool>::get_val(new, |val| {
+                    let state = segment.data.as_mut();
+                    if val {
+                        if stereo_pans.is_none() {
+                            stereo_pans = Some(enter_monaural(state));
+                        }
+                    } else if let Some(pans) = stereo_pans.take() {
+                        leave_monaural(state, &pans);
+                    }
+                    Ok(())
+                })
+                .and_then(|_| proto.write_segment(&unit.get_node(), segment, timeout_ms))
+                .map(|_| {
+                    // The pans of monitor sources are changed as well.
+                    if self.stereo_pans.is_some() != stereo_pans.is_some() {
+                        self.push_changed_elem(Self::MIXER_STREAM_SRC_PAIR_PAN_NAME);
+                        self.push_changed_elem(Self::MIXER_PHYS_SRC_PAN_NAME);
+                    }
+                    self.stereo_pans = stereo_pans;
+                    true
+                })
+            }
+            Self::MONITOR_MUTE_NAME => {
+                let mut unmuted_output_volume = self.unmuted_output_volume;
+                let mut volume_changed = false;
+                ElemValueAccessor::<bool>::get_val(new, |val| {
+                    let state = segment.data.as_mut();
+                    let muted = Self::is_monitor_muted(state, unmuted_output_volume);
+                    if val && !muted {
+                        unmuted_output_volume = Some(state.output_volume);
+                        state.output_volume = ShellMixerState::OUTPUT_VOLUME_MIN;
+                        volume_changed = true;
+                    } else if !val {
+                        if let Some(output_volume) = unmuted_output_volume.take() {
+                            if muted {
+                                state.output_volume = output_volume;
+                                volume_changed = true;
+                            }
+                        }
+                    }
+                    Ok(())
+                })
+                .and_then(|_| proto.write_segment(&unit.get_node(), segment, timeout_ms))
+                .map(|_| {
+                    self.unmuted_output_volume = unmuted_output_volume;
+                    // The volume of mixer output is changed as well.
+                    if volume_changed {
+                        self.push_changed_elem(Self::MIXER_OUT_VOL_NAME);
+                    }
+                    true
+                })
+            }
             _ => Ok(false),
         }
     }
 
+    /// Retrieve the list of elements of which value is changed by the last write operation.
+    pub fn take_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        elem_id_list.append(&mut self.changed_elem_list);
+    }
+
+    fn push_changed_elem(&mut self, name: &str) {
+        let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, name, 0);
+        self.changed_elem_list.push(elem_id);
+    }
+
     fn state_write<T, S, U, F>(unit: &SndDice, proto: &T, segment: &mut TcKonnektSegment<S>,
                             elem_value: &ElemValue, timeout_ms: u32, cb: F)
         -> Result<bool, Error>
@@ -396,13 +530,15 @@ impl ShellMixerCtl {
         .map(|_| true)
     }
 
+    // When follow_link is true, the value is written to both channels of stereo-linked pair.
     fn state_write_phys_src<T, S, U, F>(unit: &SndDice, proto: &T, segment: &mut TcKonnektSegment<S>,
-                                        new: &ElemValue, old: &ElemValue, timeout_ms: u32, cb: F)
+                                        new: &ElemValue, old: &ElemValue, timeout_ms: u32,
+                                        follow_link: bool, cb: F)
         -> Result<bool, Error>
         where T: TcKonnektSegmentProtocol<FwNode, S>,
               S: TcKonnektSegmentData + AsRef<ShellMixerState> + AsMut<ShellMixerState>,
               TcKonnektSegment<S>: TcKonnektSegmentSpec,
-              F: Fn(&mut MonitorSrcParam, U) -> Result<(), Error>,
+              F: Fn(usize, &mut MonitorSrcParam, U) -> Result<(), Error>,
               U: Default + Copy + Eq,
               ElemValue: ElemValueAccessor<U>,
     {
@@ -417,21 +553,47 @@ impl ShellMixerCtl {
             } else {
                 &mut segment.data.as_mut().digital[i - analog_count]
             };
-            let param = if ch == 0 {
-                &mut src_pair.left
+            if follow_link && src_pair.stereo_link {
+                cb(i, &mut src_pair.left, val)?;
+                cb(i, &mut src_pair.right, val)
             } else {
-                &mut src_pair.right
-            };
-            cb(param, val)
+                let param = if ch == 0 {
+                    &mut src_pair.left
+                } else {
+                    &mut src_pair.right
+                };
+                cb(i, param, val)
+            }
         })
         .and_then(|_| proto.write_segment(&unit.get_node(), segment, timeout_ms))
         .map(|_| true)
     }
 
-    pub fn read_notified_elem<S>(&self, segment: &TcKonnektSegment<S>, elem_id: &ElemId,
-                                 elem_value: &mut ElemValue)
+    fn check_stereo(&self) -> Result<(), Error> {
+        if self.stereo_pans.is_some() {
+            let msg = "The pan of monitor source is not available during monaural output";
+            Err(Error::new(CtlError::UnsupportedValue, msg))
+        } else {
+            Ok(())
+        }
+    }
+
+    // The mute is released when the volume is changed by the others, e.g. knob in front panel.
+    fn is_monitor_muted(state: &ShellMixerState, unmuted_output_volume: Option<i32>) -> bool {
+        unmuted_output_volume.is_some() && state.output_volume == ShellMixerState::OUTPUT_VOLUME_MIN
+    }
+
+    // The write to inactive pair is skipped so that the write to the other pairs in the same
+    // element is not rejected.
+    fn warn_inactive_phys_src(pair_idx: usize, rate_mode: RateMode) {
+        eprintln!("The pair {} of physical sources is not available at {} rate; skipped",
+                  pair_idx, rate_mode);
+    }
+
+    pub fn read_notified_elem<S>(&self, segment: &TcKonnektSegment<S>, rate_mode: RateMode,
+                                 elem_id: &ElemId, elem_value: &mut ElemValue)
         -> Result<bool, Error>
-        where S: TcKonnektSegmentData + AsRef<ShellMixerState>,
+        where S: TcKonnektSegmentData + ShellMixerConvert,
               TcKonnektSegment<S>: TcKonnektSegmentSpec,
     {
         match elem_id.get_name().as_str() {
@@ -461,12 +623,17 @@ impl ShellMixerCtl {
                 Ok(true)
             }
             Self::MIXER_PHYS_SRC_GAIN_NAME => {
-                Self::state_read_phys_src(segment, elem_value, |param| {
-                    Ok(param.gain_to_mixer)
+                let avail_list = Self::get_phys_src_pair_availability(segment, rate_mode);
+                Self::state_read_phys_src(segment, elem_value, |i, param| {
+                    if avail_list[i] {
+                        Ok(param.gain_to_mixer)
+                    } else {
+                        Ok(Self::LEVEL_MIN)
+                    }
                 })
             }
             Self::MIXER_PHYS_SRC_PAN_NAME => {
-                Self::state_read_phys_src(segment, elem_value, |param| {
+                Self::state_read_phys_src(segment, elem_value, |_, param| {
                     Ok(param.pan_to_mixer)
                 })
             }
@@ -478,8 +645,13 @@ impl ShellMixerCtl {
                 Ok(true)
             }
             Self::REVERB_PHYS_SRC_GAIN_NAME => {
-                Self::state_read_phys_src(segment, elem_value, |param| {
-                    Ok(param.gain_to_send)
+                let avail_list = Self::get_phys_src_pair_availability(segment, rate_mode);
+                Self::state_read_phys_src(segment, elem_value, |i, param| {
+                    if avail_list[i] {
+                        Ok(param.gain_to_send)
+                    } else {
+                        Ok(Self::LEVEL_MIN)
+                    }
                 })
             }
             Self::MIXER_OUT_DIM_NAME => {
@@ -494,6 +666,16 @@ impl ShellMixerCtl {
                 elem_value.set_int(&[segment.data.as_ref().output_dim_volume]);
                 Ok(true)
             }
+            Self::MIXER_OUT_MONO_NAME => {
+                elem_value.set_bool(&[self.stereo_pans.is_some()]);
+                Ok(true)
+            }
+            Self::MONITOR_MUTE_NAME => {
+                let muted = Self::is_monitor_muted(segment.data.as_ref(),
+                                                   self.unmuted_output_volume);
+                elem_value.set_bool(&[muted]);
+                Ok(true)
+            }
             _ => Ok(false),
         }
     }
@@ -505,19 +687,19 @@ impl ShellMixerCtl {
     {
         match elem_id.get_name().as_str() {
             Self::STREAM_IN_METER_NAME => {
-                elem_value.set_int(&segment.data.as_ref().stream_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &segment.data.as_ref().stream_inputs);
                 Ok(true)
             }
             Self::ANALOG_IN_METER_NAME => {
-                elem_value.set_int(&segment.data.as_ref().analog_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &segment.data.as_ref().analog_inputs);
                 Ok(true)
             }
             Self::DIGITAL_IN_METER_NAME => {
-                elem_value.set_int(&segment.data.as_ref().digital_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &segment.data.as_ref().digital_inputs);
                 Ok(true)
             }
             Self::MIXER_OUT_METER_NAME => {
-                elem_value.set_int(&segment.data.as_ref().main_outputs);
+                Self::METER_SCALE.set_vals(elem_value, &segment.data.as_ref().main_outputs);
                 Ok(true)
             }
             _ => Ok(false),
@@ -525,6 +707,36 @@ impl ShellMixerCtl {
     }
 }
 
+fn monitor_src_pairs_mut(state: &mut ShellMixerState)
+    -> impl Iterator<Item = &mut ShellMonitorSrcPair>
+{
+    std::iter::once(&mut state.stream)
+        .chain(state.analog.iter_mut())
+        .chain(state.digital.iter_mut())
+}
+
+// Both channels of all monitor sources are delivered to the center of mixer output, thus the mixer
+// output is monaural. The pans before are returned to restore them later.
+fn enter_monaural(state: &mut ShellMixerState) -> Vec<(i32, i32)> {
+    monitor_src_pairs_mut(state)
+        .map(|pair| {
+            let pans = (pair.left.pan_to_mixer, pair.right.pan_to_mixer);
+            pair.left.pan_to_mixer = 0;
+            pair.right.pan_to_mixer = 0;
+            pans
+        })
+        .collect()
+}
+
+fn leave_monaural(state: &mut ShellMixerState, pans: &[(i32, i32)]) {
+    monitor_src_pairs_mut(state)
+        .zip(pans.iter())
+        .for_each(|(pair, &(left, right))| {
+            pair.left.pan_to_mixer = left;
+            pair.right.pan_to_mixer = right;
+        });
+}
+
 #[derive(Default, Debug)]
 pub struct ShellReverbReturnCtl(pub Vec<ElemId>);
 
@@ -689,11 +901,13 @@ impl ShellStandaloneCtl {
                         .nth(val as usize)
                         .ok_or_else(|| {
                             let msg = format!("Invalid value for index of clock source: {}", val);
-                            Error::new(FileError::Inval, &msg)
+                            Error::new(CtlError::UnsupportedValue, &msg)
                         })
                         .map(|&s| *segment.data.as_mut() = s)
                 })
-                .and_then(|_| proto.write_segment(&unit.get_node(), segment, timeout_ms))
+                .and_then(|_| {
+                    proto.write_segment_with_verification(&unit.get_node(), segment, timeout_ms)
+                })
                 .map(|_| true)
             }
             _ => self.0.write(unit, proto, segment, elem_id, elem_value, timeout_ms),
@@ -776,7 +990,7 @@ impl MixerStreamSrcPairCtl {
                         .nth(val as usize)
                         .ok_or_else(|| {
                             let msg = format!("Invalid value for index of stream src pair: {}", val);
-                            Error::new(FileError::Inval, &msg)
+                            Error::new(CtlError::UnsupportedValue, &msg)
                         })
                         .and_then(|&s| {
                             *segment.data.as_mut() = s;
@@ -853,7 +1067,7 @@ impl ShellCoaxIfaceCtl {
                         .nth(val as usize)
                         .ok_or_else(|| {
                             let msg = format!("Invalid value for index of clock rate: {}", val);
-                            Error::new(FileError::Inval, &msg)
+                            Error::new(CtlError::UnsupportedValue, &msg)
                         })
                         .and_then(|&s| {
                             segment.data.as_mut().0 = s;
@@ -976,12 +1190,12 @@ impl ShellOptIfaceCtl {
                         .nth(val as usize)
                         .ok_or_else(|| {
                             let msg = format!("Invalid value for index of clock rate: {}", val);
-                            Error::new(FileError::Inval, &msg)
+                            Error::new(CtlError::UnsupportedValue, &msg)
                         })
                         .and_then(|&f| {
                             let mut state = segment.data.as_mut();
                             state.input_format = f;
-                            proto.write_segment(&unit.get_node(), segment, timeout_ms)
+                            proto.write_segment_with_verification(&unit.get_node(), segment, timeout_ms)
                         })
                 })
                 .map(|_| true)
@@ -992,12 +1206,12 @@ impl ShellOptIfaceCtl {
                         .nth(val as usize)
                         .ok_or_else(|| {
                             let msg = format!("Invalid value for index of clock rate: {}", val);
-                            Error::new(FileError::Inval, &msg)
+                            Error::new(CtlError::UnsupportedValue, &msg)
                         })
                         .and_then(|&f| {
                             let mut state = segment.data.as_mut();
                             state.output_format = f;
-                            proto.write_segment(&unit.get_node(), segment, timeout_ms)
+                            proto.write_segment_with_verification(&unit.get_node(), segment, timeout_ms)
                         })
                 })
                 .map(|_| true)
@@ -1008,7 +1222,7 @@ impl ShellOptIfaceCtl {
                         .nth(val as usize)
                         .ok_or_else(|| {
                             let msg = format!("Invalid value for index of clock rate: {}", val);
-                            Error::new(FileError::Inval, &msg)
+                            Error::new(CtlError::UnsupportedValue, &msg)
                         })
                         .and_then(|&s| {
                             let mut state = segment.data.as_mut();
@@ -1076,7 +1290,7 @@ impl ShellKnobCtl {
                 ElemValueAccessor::<u32>::set_val(elem_value, || {
                     let state = segment.data.as_ref();
                     if state.0 >= Self::TARGET_COUNT {
-                        let msg = format!("Unexpected value for index of program: {}", state.0);
+                        let msg = format!("Unexpected value for index of knob target: {}", state.0);
                         Err(Error::new(FileError::Io, &msg))
                     } else {
                         Ok(state.0)
@@ -1099,8 +1313,8 @@ impl ShellKnobCtl {
             Self::TARGET_NAME => {
                 ElemValueAccessor::<u32>::get_val(elem_value, |val| {
                     if val >= Self::TARGET_COUNT {
-                        let msg = format!("Invalid value for index of program: {}", val);
-                        Err(Error::new(FileError::Io, &msg))
+                        let msg = format!("Invalid value for index of knob target: {}", val);
+                        Err(Error::new(CtlError::UnsupportedValue, &msg))
                     } else {
                         segment.data.as_mut().0 = val;
                         proto.write_segment(&unit.get_node(), segment, timeout_ms)
@@ -1172,7 +1386,7 @@ impl ShellKnob2Ctl {
                 ElemValueAccessor::<u32>::set_val(elem_value, || {
                     let state = segment.data.as_ref();
                     if state.0 >= S::KNOB2_TARGET_COUNT as u32 {
-                        let msg = format!("Unexpected value for index of program: {}", state.0);
+                        let msg = format!("Unexpected value for index of knob target: {}", state.0);
                         Err(Error::new(FileError::Io, &msg))
                     } else {
                         Ok(state.0)
@@ -1195,8 +1409,8 @@ impl ShellKnob2Ctl {
             Self::KNOB2_NAME => {
                 ElemValueAccessor::<u32>::get_val(elem_value, |val| {
                     if val >= S::KNOB2_TARGET_COUNT as u32 {
-                        let msg = format!("Invalid value for index of program: {}", val);
-                        Err(Error::new(FileError::Io, &msg))
+                        let msg = format!("Invalid value for index of knob target: {}", val);
+                        Err(Error::new(CtlError::UnsupportedValue, &msg))
                     } else {
                         segment.data.as_mut().0 = val;
                         proto.write_segment(&unit.get_node(), segment, timeout_ms)
@@ -1208,3 +1422,47 @@ impl ShellKnob2Ctl {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_monaural_output() {
+        let mut state = ShellMixerState{
+            stream: Default::default(),
+            analog: vec![Default::default();2],
+            digital: vec![Default::default();3],
+            mutes: ShellMonitorSrcMute{
+                stream: Default::default(),
+                analog: vec![Default::default();4],
+                digital: vec![Default::default();6],
+            },
+            output_volume: Default::default(),
+            output_dim_enable: Default::default(),
+            output_dim_volume: Default::default(),
+        };
+        monitor_src_pairs_mut(&mut state)
+            .enumerate()
+            .for_each(|(i, pair)| {
+                pair.left.pan_to_mixer = -(i as i32) - 1;
+                pair.right.pan_to_mixer = i as i32 + 1;
+            });
+
+        let pans = enter_monaural(&mut state);
+        assert_eq!(pans.len(), 6);
+        monitor_src_pairs_mut(&mut state)
+            .for_each(|pair| {
+                assert_eq!(pair.left.pan_to_mixer, 0);
+                assert_eq!(pair.right.pan_to_mixer, 0);
+            });
+
+        leave_monaural(&mut state, &pans);
+        monitor_src_pairs_mut(&mut state)
+            .enumerate()
+            .for_each(|(i, pair)| {
+                assert_eq!(pair.left.pan_to_mixer, -(i as i32) - 1);
+                assert_eq!(pair.right.pan_to_mixer, i as i32 + 1);
+            });
+    }
+}
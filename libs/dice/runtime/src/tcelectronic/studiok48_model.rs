@@ -17,12 +17,14 @@ use dice_protocols::tcelectronic::*;
 use dice_protocols::tcelectronic::studio::*;
 
 use crate::common_ctl::*;
+use crate::meter_scale::*;
 use super::ch_strip_ctl::*;
 use super::reverb_ctl::*;
 use super::fw_led_ctl::*;
 use super::standalone_ctl::*;
 use super::midi_send_ctl::*;
 use super::prog_ctl::*;
+use super::{TcKonnektSegmentsCtl, TcKonnektModel};
 
 #[derive(Default)]
 pub struct Studiok48Model{
@@ -38,10 +40,23 @@ pub struct Studiok48Model{
     config_ctl: ConfigCtl,
     remote_ctl: RemoteCtl,
     lineout_ctl: LineoutCtl,
+    segments_ctl: TcKonnektSegmentsCtl,
 }
 
 const TIMEOUT_MS: u32 = 20;
 
+impl TcKonnektModel for Studiok48Model {
+    type Segments = StudioSegments;
+
+    fn segments(&self) -> &StudioSegments {
+        &self.segments
+    }
+
+    fn segments_ctl(&mut self) -> &mut TcKonnektSegmentsCtl {
+        &mut self.segments_ctl
+    }
+}
+
 impl CtlModel<SndDice> for Studiok48Model {
     fn load(&mut self, unit: &mut SndDice, card_cntr: &mut CardCntr) -> Result<(), Error> {
         let node = unit.get_node();
@@ -70,6 +85,7 @@ impl CtlModel<SndDice> for Studiok48Model {
         self.config_ctl.load(card_cntr)?;
         self.remote_ctl.load(card_cntr)?;
         self.lineout_ctl.load(card_cntr)?;
+        self.segments_ctl.load(card_cntr)?;
 
         Ok(())
     }
@@ -97,6 +113,8 @@ impl CtlModel<SndDice> for Studiok48Model {
             Ok(true)
         } else if self.lineout_ctl.read(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -128,10 +146,19 @@ impl CtlModel<SndDice> for Studiok48Model {
             Ok(true)
         } else if self.lineout_ctl.write(unit, &self.proto, &mut self.segments, elem_id, new, TIMEOUT_MS)? {
             Ok(true)
+        } else if self.write_segments_ctl(unit, elem_id, new)? {
+            Ok(true)
         } else {
             Ok(false)
         }
     }
+
+    fn get_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        self.ch_strip_ctl.take_changed_elem_list(elem_id_list);
+        self.reverb_ctl.take_changed_elem_list(elem_id_list);
+        self.hw_state_ctl.take_changed_elem_list(elem_id_list);
+        self.take_refreshed_elem_list(elem_id_list);
+    }
 }
 
 impl NotifyModel<SndDice, u32> for Studiok48Model {
@@ -143,6 +170,7 @@ impl NotifyModel<SndDice, u32> for Studiok48Model {
         elem_id_list.extend_from_slice(&self.phys_out_ctl.0);
         elem_id_list.extend_from_slice(&self.mixer_ctl.notified_elem_list);
         elem_id_list.extend_from_slice(&self.remote_ctl.notified_elem_list);
+        elem_id_list.extend_from_slice(&self.segments_ctl.notified_elem_list);
     }
 
     fn parse_notification(&mut self, unit: &mut SndDice, msg: &u32) -> Result<(), Error> {
@@ -176,6 +204,8 @@ impl NotifyModel<SndDice, u32> for Studiok48Model {
             Ok(true)
         } else if self.remote_ctl.read(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.read(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -188,6 +218,7 @@ impl MeasureModel<hinawa::SndDice> for Studiok48Model {
         elem_id_list.extend_from_slice(&self.ch_strip_ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.reverb_ctl.measured_elem_list);
         elem_id_list.extend_from_slice(&self.mixer_ctl.measured_elem_list);
+        elem_id_list.extend_from_slice(&self.segments_ctl.measured_elem_list);
     }
 
     fn measure_states(&mut self, unit: &mut SndDice) -> Result<(), Error> {
@@ -199,6 +230,7 @@ impl MeasureModel<hinawa::SndDice> for Studiok48Model {
 
         let node = unit.get_node();
         self.proto.read_segment(&node, &mut self.segments.mixer_meter, TIMEOUT_MS)?;
+        self.segments_ctl.measure_states(unit, &self.proto, TIMEOUT_MS)?;
 
         Ok(())
     }
@@ -214,6 +246,8 @@ impl MeasureModel<hinawa::SndDice> for Studiok48Model {
             Ok(true)
         } else if self.mixer_ctl.read_measured_elem(&self.segments, elem_id, elem_value)? {
             Ok(true)
+        } else if self.segments_ctl.measure_elem(elem_id, elem_value)? {
+            Ok(true)
         } else {
             Ok(false)
         }
@@ -275,8 +309,8 @@ impl HwStateCtl {
         card_cntr.add_bool_elems(&elem_id, 1, 1, false)
             .map(|mut elem_id_list| self.notified_elem_list.append(&mut elem_id_list))?;
 
-        self.fw_led_ctl.load(card_cntr)
-            .map(|_| self.notified_elem_list.extend_from_slice(&self.fw_led_ctl.0))?;
+        self.fw_led_ctl.load(card_cntr)?;
+        self.notified_elem_list.extend_from_slice(&self.fw_led_ctl.notified_elem_list);
 
         Ok(())
     }
@@ -300,6 +334,10 @@ impl HwStateCtl {
         self.fw_led_ctl.write(unit, proto, &mut segments.hw_state, elem_id, elem_value, timeout_ms)
     }
 
+    fn take_changed_elem_list(&mut self, elem_id_list: &mut Vec<ElemId>) {
+        self.fw_led_ctl.take_changed_elem_list(elem_id_list);
+    }
+
     fn read_notified_elem(&mut self, segments: &StudioSegments, elem_id: &ElemId, elem_value: &mut ElemValue)
         -> Result<bool, Error>
     {
@@ -939,6 +977,13 @@ impl MixerCtl {
     const LEVEL_STEP: i32 = 1;
     const LEVEL_TLV: DbInterval = DbInterval{min: -7200, max: 0, linear: false, mute_avail: false};
 
+    const METER_SCALE: MeterScale = MeterScale{
+        min: Self::LEVEL_MIN,
+        max: Self::LEVEL_MAX,
+        step: Self::LEVEL_STEP,
+        tlv: Self::LEVEL_TLV,
+    };
+
     const PAN_MIN: i32 = -50;
     const PAN_MAX: i32 = 50;
     const PAN_STEP: i32 = 1;
@@ -1049,8 +1094,7 @@ impl MixerCtl {
         -> Result<(), Error>
     {
         let elem_id = ElemId::new_by_name(ElemIfaceType::Mixer, 0, 0, name, 0);
-        card_cntr.add_int_elems(&elem_id, 1, Self::LEVEL_MIN, Self::LEVEL_MAX, Self::LEVEL_STEP,
-                                value_count, Some(&Into::<Vec<u32>>::into(Self::LEVEL_TLV)), false)
+        Self::METER_SCALE.add_int_elems(card_cntr, &elem_id, 1, value_count)
             .map(|mut elem_id_list| self.measured_elem_list.append(&mut elem_id_list))
     }
 
@@ -1390,15 +1434,15 @@ impl MixerCtl {
     {
         match elem_id.get_name().as_str() {
             Self::MIXER_INPUT_METER_NAME => {
-                elem_value.set_int(&segments.mixer_meter.data.src_inputs);
+                Self::METER_SCALE.set_vals(elem_value, &segments.mixer_meter.data.src_inputs);
                 Ok(true)
             }
             Self::MIXER_OUTPUT_METER_NAME => {
-                elem_value.set_int(&segments.mixer_meter.data.mixer_outputs);
+                Self::METER_SCALE.set_vals(elem_value, &segments.mixer_meter.data.mixer_outputs);
                 Ok(true)
             }
             Self::AUX_OUTPUT_METER_NAME => {
-                elem_value.set_int(&segments.mixer_meter.data.aux_outputs);
+                Self::METER_SCALE.set_vals(elem_value, &segments.mixer_meter.data.aux_outputs);
                 Ok(true)
             }
             _ => Ok(false),
@@ -112,7 +112,8 @@ impl RuntimeOperation<u32> for EfwRuntime {
             };
 
             match ev {
-                Event::Shutdown | Event::Disconnected => break,
+                Event::Shutdown => break,
+                Event::Disconnected => return Err(core::node_disconnected_error()),
                 Event::BusReset(generation) => {
                     println!("IEEE 1394 bus is updated: {}", generation);
                 }
@@ -153,6 +154,10 @@ impl RuntimeOperation<u32> for EfwRuntime {
         }
         Ok(())
     }
+
+    fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error> {
+        Ok(self.card_cntr.get_elem_list())
+    }
 }
 
 impl EfwRuntime {
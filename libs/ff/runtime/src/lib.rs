@@ -87,7 +87,7 @@ impl RuntimeOperation<u32> for FfRuntime {
             if let Ok(ev) = self.rx.recv() {
                 match ev {
                     Event::Shutdown => break,
-                    Event::Disconnected => break,
+                    Event::Disconnected => return Err(core::node_disconnected_error()),
                     Event::BusReset(generation) => {
                         println!("IEEE 1394 bus is updated: {}", generation);
                     }
@@ -117,6 +117,10 @@ impl RuntimeOperation<u32> for FfRuntime {
         }
         Ok(())
     }
+
+    fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error> {
+        Ok(self.card_cntr.get_elem_list())
+    }
 }
 
 impl Drop for FfRuntime {
@@ -263,7 +263,8 @@ impl<'a> V3ClkProtocol<'a> for AudioExpressProtocol {
     const HAS_LCD: bool = false;
 }
 
-/// The protocol implementation for 828mk3.
+/// The protocol implementation for 828mk3. The Hybrid variant (FireWire/USB) shares the same
+/// layout of ports and the same list of clock sources as the FireWire only variant.
 #[derive(Default)]
 pub struct F828mk3Protocol(FwReq);
 
@@ -120,7 +120,8 @@ impl<'a> RuntimeOperation<u32> for MotuRuntime {
             };
 
             match ev {
-                Event::Shutdown | Event::Disconnected => break,
+                Event::Shutdown => break,
+                Event::Disconnected => return Err(core::node_disconnected_error()),
                 Event::BusReset(generation) => {
                     println!("IEEE 1394 bus is updated: {}", generation);
                 }
@@ -135,6 +136,10 @@ impl<'a> RuntimeOperation<u32> for MotuRuntime {
         }
         Ok(())
     }
+
+    fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error> {
+        Ok(self.card_cntr.get_elem_list())
+    }
 }
 
 impl<'a> MotuRuntime {
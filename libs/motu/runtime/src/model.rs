@@ -49,7 +49,7 @@ impl MotuModel {
             0x000019 => MotuCtlModel::UltraLiteMk3(Default::default()),
             0x000033 => MotuCtlModel::AudioExpress(Default::default()),
             0x000015 |  // Firewire only.
-            0x000035 => MotuCtlModel::F828mk3(Default::default()),
+            0x000035 => MotuCtlModel::F828mk3(Default::default()), // Hybrid.
             0x000045 => MotuCtlModel::H4pre(Default::default()),
             _ => {
                 let label = format!("Unsupported model ID: 0x{:06x}", model_id);
@@ -136,3 +136,40 @@ pub fn clk_rate_to_string(rate: &ClkRate) -> String {
         ClkRate::R192000 => "192000",
     }.to_string()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The device is not required since each model adds the elements according to its protocol
+    // only.
+    fn get_elem_list(model_id: u32)
+        -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error>
+    {
+        let mut model = MotuModel::new(model_id, 0)?;
+        let mut unit = hinawa::SndMotu::new();
+        let mut card_cntr = CardCntr::new_without_card();
+        model.load(&mut unit, &mut card_cntr)?;
+        Ok(card_cntr.get_elem_list())
+    }
+
+    #[test]
+    fn elem_list_without_device() {
+        [0x000001, 0x000002, 0x000003, 0x000009, 0x00000d, 0x00000f, 0x000019, 0x000033,
+         0x000015, 0x000035, 0x000045].iter().for_each(|&model_id| {
+            let elem_list = get_elem_list(model_id).unwrap();
+            assert!(elem_list.iter().any(|(elem_id, _)| elem_id.get_name() == "clock-source"),
+                    "model 0x{:06x}", model_id);
+
+            let mut names: Vec<(String, u32)> = elem_list.iter()
+                .map(|(elem_id, _)| (elem_id.get_name().to_string(), elem_id.get_index()))
+                .collect();
+            let count = names.len();
+            names.sort();
+            names.dedup();
+            assert_eq!(names.len(), count, "model 0x{:06x}", model_id);
+        });
+
+        assert!(get_elem_list(0x000000).is_err());
+    }
+}
@@ -96,6 +96,8 @@ impl<'a> V3ClkCtl {
     {
         match elem_id.get_name().as_str() {
             Self::RATE_NAME => {
+                // NOTE: no register is known to report the rate detected in signal from external
+                // source, thus the requested rate is not checked against it.
                 ElemValueAccessor::<u32>::get_val(new, |val| {
                     unit.lock()?;
                     let res = proto.set_clk_rate(unit, val as usize, timeout_ms);
@@ -11,6 +11,9 @@ use ta1394::*;
 use ta1394::general::*;
 use ta1394::stream_format::*;
 use ta1394::amdtp::*;
+use ta1394::audio::FeatureCtl;
+
+use alsa_ctl_tlv_codec::items::DbInterval;
 
 #[derive(Default, Debug)]
 pub struct CommonCtl{
@@ -279,3 +282,15 @@ impl<'a> CommonCtl {
         Ok(entries)
     }
 }
+
+/// Build data of TLV for the range of volume in AV/C audio function block. The negative infinity
+/// as minimum value is available to mute.
+pub fn build_volume_tlv(min: i16, max: i16) -> Vec<u32> {
+    let interval = DbInterval{
+        min: FeatureCtl::volume_to_db(min),
+        max: FeatureCtl::volume_to_db(max),
+        linear: false,
+        mute_avail: FeatureCtl::is_volume_mute(min),
+    };
+    interval.into()
+}
@@ -12,13 +12,14 @@ use core::elem_value_accessor::ElemValueAccessor;
 use ta1394::Ta1394Avc;
 use ta1394::audio::{AUDIO_SUBUNIT_0_ADDR, AudioFeature, CtlAttr, FeatureCtl, AudioCh};
 
-use super::common_ctl::CommonCtl;
+use super::common_ctl::{CommonCtl, build_volume_tlv};
 
 #[derive(Default, Debug)]
 pub struct GriffinModel {
     avc: hinawa::FwFcp,
     common_ctl: CommonCtl,
     voluntary: bool,
+    vol_min: i16,
 }
 
 impl<'a> GriffinModel {
@@ -49,6 +50,7 @@ impl card_cntr::CtlModel<hinawa::SndUnit> for GriffinModel {
                 FeatureCtl::Volume(data) => data[0],
                 _ => unreachable!(),
             };
+            self.vol_min = min;
 
             let mut op = AudioFeature::new(Self::VOL_FB_ID, CtlAttr::Maximum, AudioCh::All,
                                            FeatureCtl::Volume(vec![-1]));
@@ -68,8 +70,9 @@ impl card_cntr::CtlModel<hinawa::SndUnit> for GriffinModel {
 
             let elem_id = alsactl::ElemId::new_by_name(alsactl::ElemIfaceType::Mixer,
                                                        0, 0, Self::VOL_LABEL, 0);
+            let tlv = build_volume_tlv(min, max);
             let _ = card_cntr.add_int_elems(&elem_id, 1, min as i32, max as i32, step as i32,
-                                            Self::CHANNEL_MAP.len(), None, true)?;
+                                            Self::CHANNEL_MAP.len(), Some(&tlv), true)?;
 
             let elem_id = alsactl::ElemId::new_by_name(alsactl::ElemIfaceType::Mixer,
                                                        0, 0, Self::MUTE_LABEL, 0);
@@ -92,7 +95,7 @@ impl card_cntr::CtlModel<hinawa::SndUnit> for GriffinModel {
                                             AudioCh::Each(idx as u8), FeatureCtl::Volume(vec![-1]));
                         self.avc.status(&AUDIO_SUBUNIT_0_ADDR, &mut op, Self::FCP_TIMEOUT_MS)?;
                         if let FeatureCtl::Volume(data) = op.ctl {
-                            Ok(data[0] as i32)
+                            Ok(FeatureCtl::volume_to_ctl_value(data[0], self.vol_min))
                         } else {
                             unreachable!();
                         }
@@ -128,8 +131,10 @@ impl card_cntr::CtlModel<hinawa::SndUnit> for GriffinModel {
             match elem_id.get_name().as_str() {
                 Self::VOL_LABEL => {
                     ElemValueAccessor::<i32>::get_vals(new, old, Self::CHANNEL_MAP.len(), |idx, val| {
+                        let vol = FeatureCtl::volume_from_ctl_value(val, self.vol_min);
                         let mut op = AudioFeature::new(Self::VOL_FB_ID, CtlAttr::Current,
-                                            AudioCh::Each(idx as u8), FeatureCtl::Volume(vec![val as i16]));
+                                                       AudioCh::Each(idx as u8),
+                                                       FeatureCtl::Volume(vec![vol]));
                         self.avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, Self::FCP_TIMEOUT_MS)
                     })?;
                     Ok(true)
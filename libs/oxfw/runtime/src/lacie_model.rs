@@ -12,13 +12,14 @@ use core::elem_value_accessor::ElemValueAccessor;
 use ta1394::Ta1394Avc;
 use ta1394::audio::{AUDIO_SUBUNIT_0_ADDR, AudioFeature, CtlAttr, FeatureCtl, AudioCh};
 
-use super::common_ctl::CommonCtl;
+use super::common_ctl::{CommonCtl, build_volume_tlv};
 
 #[derive(Default, Debug)]
 pub struct LacieModel {
     avc: hinawa::FwFcp,
     common_ctl: CommonCtl,
     voluntary: bool,
+    vol_min: i16,
 }
 
 impl<'a> LacieModel {
@@ -47,6 +48,7 @@ impl card_cntr::CtlModel<hinawa::SndUnit> for LacieModel {
                 FeatureCtl::Volume(data) => data[0],
                 _ => unreachable!(),
             };
+            self.vol_min = min;
 
             let mut op = AudioFeature::new(Self::FB_ID, CtlAttr::Maximum, AudioCh::All,
                                            FeatureCtl::Volume(vec![-1]));
@@ -66,8 +68,9 @@ impl card_cntr::CtlModel<hinawa::SndUnit> for LacieModel {
 
             let elem_id = alsactl::ElemId::new_by_name(alsactl::ElemIfaceType::Mixer,
                                                        0, 0, Self::VOL_LABEL, 0);
+            let tlv = build_volume_tlv(min, max);
             let _ = card_cntr.add_int_elems(&elem_id, 1, min as i32, max as i32, step as i32,
-                                            1, None, true)?;
+                                            1, Some(&tlv), true)?;
 
             let elem_id = alsactl::ElemId::new_by_name(alsactl::ElemIfaceType::Mixer,
                                                        0, 0, Self::MUTE_LABEL, 0);
@@ -90,7 +93,7 @@ impl card_cntr::CtlModel<hinawa::SndUnit> for LacieModel {
                                                        FeatureCtl::Volume(vec![-1]));
                         self.avc.status(&AUDIO_SUBUNIT_0_ADDR, &mut op, Self::FCP_TIMEOUT_MS)?;
                         if let FeatureCtl::Volume(data) = op.ctl {
-                            Ok(data[0] as i32)
+                            Ok(FeatureCtl::volume_to_ctl_value(data[0], self.vol_min))
                         } else {
                             unreachable!();
                         }
@@ -126,8 +129,9 @@ impl card_cntr::CtlModel<hinawa::SndUnit> for LacieModel {
             match elem_id.get_name().as_str() {
                 Self::VOL_LABEL => {
                     ElemValueAccessor::<i32>::get_val(new, |val| {
+                        let vol = FeatureCtl::volume_from_ctl_value(val, self.vol_min);
                         let mut op = AudioFeature::new(Self::FB_ID, CtlAttr::Current, AudioCh::All,
-                                                       FeatureCtl::Volume(vec![val as i16]));
+                                                       FeatureCtl::Volume(vec![vol]));
                         self.avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, Self::FCP_TIMEOUT_MS)
                     })?;
                     Ok(true)
@@ -133,7 +133,7 @@ impl<'a> RuntimeOperation<u32> for OxfwRuntime {
 
             match ev {
                 Event::Shutdown => break,
-                Event::Disconnected => break,
+                Event::Disconnected => return Err(core::node_disconnected_error()),
                 Event::BusReset(generation) => {
                     println!("IEEE 1394 bus is updated: {}", generation);
                 }
@@ -164,6 +164,10 @@ impl<'a> RuntimeOperation<u32> for OxfwRuntime {
         }
         Ok(())
     }
+
+    fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error> {
+        Ok(self.card_cntr.get_elem_list())
+    }
 }
 
 impl<'a> OxfwRuntime {
@@ -394,6 +394,36 @@ impl FeatureCtl {
 
     pub const INFINITY: i16 = 0x7ffeu16 as i16;
     pub const NEG_INFINITY: i16 = 0x8000u16 as i16;
+
+    /// Convert the value of volume in 1/256 dB unit into the value in 0.01 dB unit.
+    pub fn volume_to_db(val: i16) -> i32 {
+        val as i32 * 100 / 256
+    }
+
+    /// Whether the value of volume means mute, which is the negative infinity.
+    pub fn is_volume_mute(val: i16) -> bool {
+        val == Self::NEG_INFINITY
+    }
+
+    /// Convert the value of volume into the value of control element with the minimum value.
+    /// The negative infinity and any value below the minimum are converted into the minimum value.
+    pub fn volume_to_ctl_value(val: i16, min: i16) -> i32 {
+        if Self::is_volume_mute(val) || val < min {
+            min as i32
+        } else {
+            val as i32
+        }
+    }
+
+    /// Convert the value of control element with the minimum value into the value of volume. The
+    /// minimum value is converted into the negative infinity when it means mute.
+    pub fn volume_from_ctl_value(val: i32, min: i16) -> i16 {
+        if val <= min as i32 {
+            min
+        } else {
+            val as i16
+        }
+    }
 }
 
 impl From<&FeatureCtl> for AudioFuncBlkCtl {
@@ -1027,4 +1057,18 @@ mod test {
         assert_eq!(AudioCh::Each(0x43), op.output_ch);
         assert_eq!(ctl, op.ctl);
     }
+
+    #[test]
+    fn volume_ctl_value() {
+        let min = FeatureCtl::NEG_INFINITY;
+        assert_eq!(FeatureCtl::volume_to_ctl_value(FeatureCtl::NEG_INFINITY, min), min as i32);
+        assert_eq!(FeatureCtl::volume_to_ctl_value(-0x100, min), -0x100);
+        assert_eq!(FeatureCtl::volume_from_ctl_value(min as i32, min), FeatureCtl::NEG_INFINITY);
+        assert_eq!(FeatureCtl::volume_from_ctl_value(-0x100, min), -0x100);
+
+        let min = -0x7f00;
+        assert_eq!(FeatureCtl::volume_to_ctl_value(FeatureCtl::NEG_INFINITY, min), min as i32);
+        assert_eq!(FeatureCtl::volume_from_ctl_value(min as i32, min), min);
+        assert_eq!(FeatureCtl::volume_from_ctl_value(-0x100, min), -0x100);
+    }
 }
@@ -279,7 +279,8 @@ impl<'a> AsyncRuntime {
             };
 
             match ev {
-                AsyncUnitEvent::Shutdown | AsyncUnitEvent::Disconnected => break,
+                AsyncUnitEvent::Shutdown => break,
+                AsyncUnitEvent::Disconnected => return Err(core::node_disconnected_error()),
                 AsyncUnitEvent::BusReset(generation) => {
                     println!("IEEE 1394 bus is updated: {}", generation);
                 }
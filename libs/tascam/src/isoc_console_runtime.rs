@@ -243,7 +243,7 @@ impl<'a> IsocConsoleRuntime<'a> {
 
             match ev {
                 ConsoleUnitEvent::Shutdown => break,
-                ConsoleUnitEvent::Disconnected => break,
+                ConsoleUnitEvent::Disconnected => return Err(core::node_disconnected_error()),
                 ConsoleUnitEvent::BusReset(generation) => {
                     println!("IEEE 1394 bus is updated: {}", generation);
                 }
@@ -290,6 +290,10 @@ impl<'a> IsocConsoleRuntime<'a> {
         Ok(())
     }
 
+    pub fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error> {
+        Ok(self.card_cntr.get_elem_list())
+    }
+
     fn update_led_if_needed(&mut self, pos: u16, state: bool) -> Result<(), Error> {
         let node = self.unit.get_node();
 
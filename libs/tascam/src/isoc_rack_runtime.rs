@@ -178,7 +178,7 @@ impl<'a> IsocRackRuntime<'a> {
 
             match ev {
                 RackUnitEvent::Shutdown => break,
-                RackUnitEvent::Disconnected => break,
+                RackUnitEvent::Disconnected => return Err(core::node_disconnected_error()),
                 RackUnitEvent::BusReset(generation) => {
                     println!("IEEE 1394 bus is updated: {}", generation);
                 }
@@ -208,4 +208,8 @@ impl<'a> IsocRackRuntime<'a> {
 
         Ok(())
     }
+
+    pub fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error> {
+        Ok(self.card_cntr.get_elem_list())
+    }
 }
@@ -91,6 +91,15 @@ impl<'a> RuntimeOperation<(String, u32)> for TascamRuntime<'a> {
             Self::Async(unit) => unit.run(),
         }
     }
+
+    fn get_elem_list(&self) -> Result<Vec<(alsactl::ElemId, alsactl::ElemType)>, Error> {
+        match self {
+            Self::IsocConsole(unit) => unit.get_elem_list(),
+            Self::IsocRack(unit) => unit.get_elem_list(),
+            // The unit is controlled by ALSA sequencer only.
+            Self::Async(_) => Ok(Vec::new()),
+        }
+    }
 }
 
 fn detect_model_name<'a>(entries: &'a [Entry]) -> Result<&'a str, Error> {
@@ -101,7 +110,7 @@ fn detect_model_name<'a>(entries: &'a [Entry]) -> Result<&'a str, Error> {
                     EntryDataAccess::<&[Entry]>::get(entry, KeyType::DependentInfo)
                         .and_then(|entries| {
                             entries.iter().find_map(|entry| {
-                                EntryDataAccess::<&str>::get(entry, KeyType::BusDependentInfo)
+                                parse_leaf_entry_as_text(entry, KeyType::BusDependentInfo)
                             })
                         })
                 })
@@ -112,3 +121,31 @@ fn detect_model_name<'a>(entries: &'a [Entry]) -> Result<&'a str, Error> {
         Error::new(FileError::Nxio, &label)
     })
 }
+
+// NOTE: some units have leaf of model name without the header of textual descriptor, or with
+// padding by space. Parse it as ASCII characters for fallback.
+fn parse_leaf_entry_as_text<'a>(entry: &'a Entry, key_type: KeyType) -> Option<&'a str> {
+    EntryDataAccess::<&str>::get(entry, key_type)
+        .or_else(|| {
+            if entry.key != key_type {
+                return None;
+            }
+
+            if let EntryData::Leaf(raw) = &entry.data {
+                // Skip the header of textual descriptor if exists.
+                let literal = if raw.len() > 8 && raw[..8].iter().all(|&b| b == 0x00) {
+                    &raw[8..]
+                } else {
+                    &raw[..]
+                };
+                let pos = literal.iter().position(|&b| b == 0x00).unwrap_or(literal.len());
+                std::str::from_utf8(&literal[..pos])
+                    .ok()
+                    .filter(|text| text.is_ascii())
+            } else {
+                None
+            }
+        })
+        .map(|text| text.trim_end_matches(|c| c == '\0' || c == ' '))
+        .filter(|text| text.len() > 0)
+}
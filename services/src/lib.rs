@@ -19,6 +19,13 @@ pub trait ServiceCmd<'a, T, R> : Sized
 {
     const CMD_NAME: &'a str;
     const ARGS: &'a [(&'a str, &'a str)];
+    /// The flag to print raw content of registers in the unit, then exit.
+    const DUMP_FLAG: &'a str = "--dump";
+    /// The flag to print the list of ALSA control elements added by the runtime, then exit.
+    const LIST_ELEMS_FLAG: &'a str = "--list-elems";
+    /// The flags to print help message, then exit.
+    const HELP_FLAGS: &'a [&'a str] = &["--help", "-h"];
+
     fn parse_args(args: &[String]) -> Result<T, String>;
 
     fn print_help() {
@@ -33,11 +40,101 @@ Usage:
 
         Self::ARGS.iter().for_each(|entry| {
             println!("    {}: {}", entry.0, entry.1);
-        })
+        });
+
+        println!("
+  Optionally, {} prints raw content of registers in the unit and exits.",
+                 Self::DUMP_FLAG);
+
+        println!("
+  Optionally, {} prints the list of ALSA control elements added by the runtime and exits.",
+                 Self::LIST_ELEMS_FLAG);
+
+        println!("
+  {} prints this message and exits.",
+                 Self::HELP_FLAGS.join(" or "));
+    }
+
+    fn open(args: T) -> Result<R, String> {
+        R::new(args)
+            .map_err(|e| {
+                let (domain, cause) = if let Some(error) = e.kind::<FileError>() {
+                    ("Linux file operation error", match error {
+                        FileError::Acces => "Access permission",
+                        FileError::Isdir => "Is directory",
+                        FileError::Noent => "Not exists",
+                        _ => "",
+                    })
+                } else if let Some(error) = e.kind::<SndUnitError>() {
+                    ("ALSA HwDep operation error", match error {
+                        SndUnitError::Disconnected => "Sound card is disconnected",
+                        SndUnitError::Used => "ALSA Hwdep device is already used",
+                        SndUnitError::WrongClass => "Unit is not for the runtime",
+                        _ => "",
+                    })
+                } else if let Some(error) = e.kind::<FwNodeError>() {
+                    ("Linux FireWire node operation error", match error {
+                        FwNodeError::Disconnected => "Node is disconnected",
+                        _ => "",
+                    })
+                } else if let Some(error) = e.kind::<CardError>() {
+                    ("ALSA control operation error", match error {
+                        CardError::Disconnected => "Sound card is disconnected",
+                        _ => "",
+                    })
+                } else if e.is::<UserClientError>() {
+                    ("ALSA Sequencer operation error", "")
+                } else {
+                    ("Unknown domain error", "")
+                };
+                format!("{}: {}, {}", domain, cause, e)
+            })
+    }
+
+    fn launch(args: T) -> Result<R, String> {
+        Self::open(args)
+            .and_then(|mut runtime| {
+                runtime.listen()
+                    .map_err(|e| format!("Fail to listen to events: {}", e))
+                    .map(|_| runtime)
+            })
+    }
+
+    // The runtime is not listened to events, thus the unit is not locked for streaming.
+    fn dump(args: T) -> Result<(), String> {
+        Self::open(args)
+            .and_then(|mut runtime| {
+                runtime.dump()
+                    .map_err(|e| format!("Fail to dump registers: {}", e))
+            })
+    }
+
+    // The elements are removed when the runtime is dropped.
+    fn list_elems(args: T) -> Result<(), String> {
+        Self::launch(args)
+            .and_then(|runtime| {
+                runtime.get_elem_list()
+                    .map_err(|e| format!("Fail to retrieve the list of elements: {}", e))
+            })
+            .map(|elem_list| {
+                elem_list.iter().for_each(|(elem_id, elem_type)| {
+                    println!("{:?}\t{}\t{}\t{:?}", elem_id.get_iface(), elem_id.get_name(),
+                             elem_id.get_index(), elem_type);
+                });
+            })
     }
 
     fn run() {
-        let args: Vec<String> = std::env::args().skip(1).collect();
+        let mut args: Vec<String> = std::env::args().skip(1).collect();
+        if args.iter().any(|arg| Self::HELP_FLAGS.iter().any(|&flag| arg == flag)) {
+            Self::print_help();
+            std::process::exit(libc::EXIT_SUCCESS)
+        }
+
+        let pos = args.iter().position(|arg| arg == Self::DUMP_FLAG);
+        let dump = pos.map(|pos| args.remove(pos)).is_some();
+        let pos = args.iter().position(|arg| arg == Self::LIST_ELEMS_FLAG);
+        let list_elems = pos.map(|pos| args.remove(pos)).is_some();
         let code =
             (if args.len() < Self::ARGS.len() {
                 let msg = if Self::ARGS.len() == 1 {
@@ -50,49 +147,27 @@ Usage:
                 Self::parse_args(&args)
             })
             .and_then(|args| {
-                R::new(args)
+                if dump {
+                    return Self::dump(args);
+                }
+
+                if list_elems {
+                    return Self::list_elems(args);
+                }
+
+                // NOTE: The sound card can be added with the other index after re-enumeration, and
+                // the service manager launches the other instance for it. The program just exits
+                // at disconnection and leaves restart to the service manager.
+                Self::launch(args)?
+                    .run()
                     .map_err(|e| {
-                        let (domain, cause) = if let Some(error) = e.kind::<FileError>() {
-                            ("Linux file operation error", match error {
-                                FileError::Acces => "Access permission",
-                                FileError::Isdir => "Is directory",
-                                FileError::Noent => "Not exists",
-                                _ => "",
-                            })
-                        } else if let Some(error) = e.kind::<SndUnitError>() {
-                            ("ALSA HwDep operation error", match error {
-                                SndUnitError::Disconnected => "Sound card is disconnected",
-                                SndUnitError::Used => "ALSA Hwdep device is already used",
-                                SndUnitError::WrongClass => "Unit is not for the runtime",
-                                _ => "",
-                            })
-                        } else if let Some(error) = e.kind::<FwNodeError>() {
-                            ("Linux FireWire node operation error", match error {
-                                FwNodeError::Disconnected => "Node is disconnected",
-                                _ => "",
-                            })
-                        } else if let Some(error) = e.kind::<CardError>() {
-                            ("ALSA control operation error", match error {
-                                CardError::Disconnected => "Sound card is disconnected",
-                                _ => "",
-                            })
-                        } else if e.is::<UserClientError>() {
-                            ("ALSA Sequencer operation error", "")
+                        if e.kind::<FwNodeError>() == Some(FwNodeError::Disconnected) {
+                            format!("The node for the unit is disconnected: {}", e)
                         } else {
-                            ("Unknown domain error", "")
-                        };
-                        format!("{}: {}, {}", domain, cause, e)
+                            format!("Finish by error: {}", e)
+                        }
                     })
             })
-            .and_then(|mut runtime| {
-                runtime.listen()
-                    .map_err(|e| format!("Fail to listen to events: {}", e))
-                    .map(|_| runtime)
-            })
-            .and_then(|mut runtime| {
-                runtime.run()
-                    .map_err(|e| format!("Finish by error: {}", e))
-            })
             .map(|_| libc::EXIT_SUCCESS)
             .unwrap_or_else(|msg| {
                 eprintln!("{}", msg);